@@ -1,70 +1,83 @@
-#![recursion_limit = "512"]
+#![recursion_limit = "1024"]
 
 extern crate proc_macro;
 
 use pmutil::{Quote, ToTokensExt, smart_quote};
-use quote::{ToTokens, quote};
+use quote::quote;
 use syn::{
-    Data, DataEnum, DeriveInput, Field, Generics, Ident, WhereClause, WherePredicate,
-    punctuated::Punctuated, token::Comma,
+    Data, DeriveInput, Field, Generics, Ident, Variant, Visibility, WhereClause, WherePredicate,
+    ext::IdentExt, parse_macro_input, punctuated::Punctuated, token::Comma,
 };
 
-#[proc_macro_derive(BoolLike)]
+#[proc_macro_derive(BoolLike, attributes(bool_like))]
 pub fn bool_like(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let input: DeriveInput = syn::parse(input).expect("failed to parse derive input");
-
-    let data = match input.data {
-        Data::Enum(ref data) => data,
-        _ => panic!("`BoolLike` can be applied only on enums"),
-    };
-
-    expand(&input, BoolLike, data)
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, BoolLike)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-#[proc_macro_derive(OptionLike)]
+#[proc_macro_derive(OptionLike, attributes(option_like))]
 pub fn option_like(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let input: DeriveInput = syn::parse(input).expect("failed to parse derive input");
-
-    let data = match input.data {
-        Data::Enum(ref data) => data,
-        _ => panic!("`OptionLike` can be applied only on enums"),
-    };
-
-    expand(&input, OptionLike, data)
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, OptionLike)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-#[proc_macro_derive(ResultLike)]
+#[proc_macro_derive(ResultLike, attributes(result_like))]
 pub fn result_like(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let input: DeriveInput = syn::parse(input).expect("failed to parse derive input");
-
-    let data = match input.data {
-        Data::Enum(ref data) => data,
-        _ => panic!("`ResultLike` can be applied only on enums"),
-    };
-
-    expand(&input, ResultLike, data)
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, ResultLike)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum VariantFieldsType {
-    Unnamed,
+    Payload,
     Unit,
 }
 
 impl VariantFieldsType {
-    fn matches(self, fields: &syn::Fields) -> bool {
-        #[allow(clippy::match_like_matches_macro)]
-        match (self, fields) {
-            (VariantFieldsType::Unnamed, syn::Fields::Unnamed(_)) => true,
-            (VariantFieldsType::Unit, syn::Fields::Unit) => true,
-            _ => false,
+    fn check(self, like_name: &str, position: &str, variant: &Variant) -> syn::Result<()> {
+        match (self, &variant.fields) {
+            (VariantFieldsType::Payload, syn::Fields::Unnamed(fields))
+                if !fields.unnamed.is_empty() =>
+            {
+                Ok(())
+            }
+            (VariantFieldsType::Payload, syn::Fields::Named(fields)) if fields.named.len() == 1 => {
+                Ok(())
+            }
+            (VariantFieldsType::Unit, syn::Fields::Unit) => Ok(()),
+            (VariantFieldsType::Payload, syn::Fields::Unit) => Err(syn::Error::new_spanned(
+                variant,
+                format!("`{like_name}` requires the {position} variant to carry a field"),
+            )),
+            (VariantFieldsType::Payload, fields @ syn::Fields::Named(_)) => {
+                Err(syn::Error::new_spanned(
+                    fields,
+                    format!(
+                        "`{like_name}` requires the {position} variant to carry exactly one named field"
+                    ),
+                ))
+            }
+            (VariantFieldsType::Payload, fields) => Err(syn::Error::new_spanned(
+                fields,
+                format!("`{like_name}` requires the {position} variant to carry a field"),
+            )),
+            (VariantFieldsType::Unit, fields) => Err(syn::Error::new_spanned(
+                fields,
+                format!("`{like_name}` requires the {position} variant to be a unit variant"),
+            )),
         }
     }
 }
 
 trait LikeTrait {
     fn data(&self) -> LikeData;
-    fn quote_impl(&self, args: ImplArgs) -> Quote;
+    fn quote_impl(&self, args: ImplArgs) -> syn::Result<Quote>;
 }
 
 struct ImplArgs<'a> {
@@ -72,11 +85,45 @@ struct ImplArgs<'a> {
     generics: &'a Generics,
     primary: &'a Ident,
     secondary: &'a Ident,
-    primary_inner: Option<&'a Punctuated<Field, Comma>>,
-    secondary_inner: Option<&'a Punctuated<Field, Comma>>,
+    primary_fields: &'a syn::Fields,
+    secondary_fields: &'a syn::Fields,
+    krate: &'a syn::Path,
+    aliases: bool,
+    /// Implement `Try` and `FromResidual`; see `ContainerAttrs::try_trait`.
+    try_trait: bool,
 }
 
 impl<'a> ImplArgs<'a> {
+    /// A variant-named method, e.g. `unwrap_{}` for `Found` is `unwrap_found`.
+    /// It's spanned to the variant so that collisions point there.
+    fn alias(&self, variant: &Ident, template: &str) -> syn::Result<Ident> {
+        let name = template.replace("{}", &snake_case(&variant.unraw().to_string()));
+        let mut alias: Ident = syn::parse_str(&name).map_err(|_| {
+            syn::Error::new_spanned(
+                variant,
+                format!("`aliases` can't name a method `{name}` after this variant"),
+            )
+        })?;
+        alias.set_span(variant.span());
+        Ok(alias)
+    }
+
+    fn primary_payload(&self) -> Payload<'a> {
+        Payload {
+            typ: self.typ,
+            variant: self.primary,
+            fields: self.primary_fields,
+        }
+    }
+
+    fn secondary_payload(&self) -> Payload<'a> {
+        Payload {
+            typ: self.typ,
+            variant: self.secondary,
+            fields: self.secondary_fields,
+        }
+    }
+
     fn split_for_impl(
         &self,
     ) -> (
@@ -90,7 +137,7 @@ impl<'a> ImplArgs<'a> {
             syn::parse2::<Generics>(impl_generics.dump()).expect("generics to generics");
         let ty_generics =
             syn::parse2::<Generics>(ty_generics.dump()).expect("generics to generics");
-        let where_predicates = where_clause.map_or(
+        let mut where_predicates = where_clause.map_or(
             WhereClause {
                 where_token: Default::default(),
                 predicates: Default::default(),
@@ -98,61 +145,703 @@ impl<'a> ImplArgs<'a> {
             .predicates,
             |w| w.predicates.clone(),
         );
+        // Templates append their own predicates after `where_predicates`.
+        if !where_predicates.empty_or_trailing() {
+            where_predicates.push_punct(Default::default());
+        }
         (impl_generics, ty_generics, where_clause, where_predicates)
     }
+
+    /// The type parameter `inner` consists of, either bare or as the element
+    /// of an array, when it can be replaced by another type without touching
+    /// the `others` payloads. Its bounds move along; see
+    /// [`ImplArgs::rebound_predicates`].
+    ///
+    /// A bound on a shorthand projection like `T::Item: Debug` keeps the
+    /// parameter in place: `T::Item` can't be rewritten for `&T` without
+    /// knowing the trait, so the enum gets the std-returning methods instead.
+    /// `<T as Iterator>::Item: Debug` can be rewritten.
+    fn rebindable_param(
+        &self,
+        inner: &'a syn::Fields,
+        others: &[&syn::Fields],
+    ) -> Option<Rebindable<'a>> {
+        let [field] = inner.iter().collect::<Vec<_>>()[..] else {
+            return None;
+        };
+        let (elem, len) = match &field.ty {
+            syn::Type::Array(array) => (&*array.elem, Some(&array.len)),
+            ty => (ty, None),
+        };
+        let syn::Type::Path(syn::TypePath { qself: None, path }) = elem else {
+            return None;
+        };
+        let param = path
+            .get_ident()
+            .and_then(|ident| self.generics.type_params().find(|p| &p.ident == ident))?;
+        let ident = &param.ident;
+        if others.iter().any(|fields| mentions(fields.dump(), ident))
+            || len.is_some_and(|len| mentions(len.dump(), ident))
+            || self
+                .bound_predicates()
+                .into_iter()
+                .any(|predicate| shorthand_projection(predicate, ident))
+        {
+            return None;
+        }
+        Some(Rebindable { param: ident, len })
+    }
+
+    /// The inline bounds of the type parameters as predicates, followed by the
+    /// where clause.
+    fn bound_predicates(&self) -> Vec<proc_macro2::TokenStream> {
+        let inline = self
+            .generics
+            .type_params()
+            .filter(|p| !p.bounds.is_empty())
+            .map(|p| {
+                let (ident, bounds) = (&p.ident, &p.bounds);
+                quote!(#ident: #bounds)
+            });
+        let predicates = self
+            .generics
+            .where_clause
+            .iter()
+            .flat_map(|w| w.predicates.iter().map(ToTokensExt::dump));
+        inline.chain(predicates).collect()
+    }
+
+    /// The predicates `Type<..>` needs once each parameter of `replacements`
+    /// is replaced, e.g. `U: Ord + Hash,` for `T: Ord` and `where T: Hash`.
+    /// Every predicate ends with a comma.
+    fn rebound_predicates(
+        &self,
+        replacements: &[(&Ident, proc_macro2::TokenStream)],
+    ) -> proc_macro2::TokenStream {
+        let predicates = self
+            .bound_predicates()
+            .into_iter()
+            .filter(|p| {
+                replacements
+                    .iter()
+                    .any(|(param, _)| mentions(p.clone(), param))
+            })
+            .map(|p| replace_params(p, replacements));
+        quote!(#(#predicates,)*)
+    }
+
+    /// `impl_generics` with an `extra` parameter appended, or prepended if
+    /// it's a lifetime.
+    fn impl_generics_with(
+        &self,
+        impl_generics: &Generics,
+        extra: proc_macro2::TokenStream,
+    ) -> Generics {
+        let mut generics = impl_generics.clone();
+        let param = syn::parse2(extra).expect("extra generic parameter");
+        if let syn::GenericParam::Lifetime(_) = param {
+            generics.params.insert(0, param);
+        } else {
+            generics.params.push(param);
+        }
+        generics
+    }
+
+    /// `ty_generics` with each parameter of `replacements` replaced in place.
+    fn rebind_generics(
+        &self,
+        replacements: &[(&Ident, proc_macro2::TokenStream)],
+    ) -> proc_macro2::TokenStream {
+        let args = self.generics.params.iter().map(|p| match p {
+            syn::GenericParam::Lifetime(l) => l.lifetime.dump(),
+            syn::GenericParam::Type(t) => replacements
+                .iter()
+                .find(|(param, _)| *param == &t.ident)
+                .map_or_else(|| t.ident.dump(), |(_, with)| with.clone()),
+            syn::GenericParam::Const(c) => c.ident.dump(),
+        });
+        quote!(<#(#args),*>)
+    }
+
+    /// `const` for methods that move `fields` around. Stable `const fn` can't
+    /// drop anything that might need dropping, so this is only given when the
+    /// payload types are known to be `Copy`.
+    fn const_if_copy(&self, fields: &[&syn::Fields]) -> Option<syn::token::Const> {
+        fields
+            .iter()
+            .flat_map(|fields| fields.iter())
+            .all(|field| self.is_known_copy(&field.ty))
+            .then(Default::default)
+    }
+
+    /// Whether `ty` is a primitive, a pointer, or bounded by `Copy` on the
+    /// enum, possibly nested in arrays and tuples.
+    fn is_known_copy(&self, ty: &syn::Type) -> bool {
+        const PRIMITIVES: &[&str] = &[
+            "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16",
+            "u32", "u64", "u128", "usize",
+        ];
+        let is_copy_bound = |bound: &syn::TypeParamBound| match bound {
+            syn::TypeParamBound::Trait(t) => {
+                t.path.segments.last().is_some_and(|s| s.ident == "Copy")
+            }
+            _ => false,
+        };
+        match ty {
+            syn::Type::Reference(_)
+            | syn::Type::Ptr(_)
+            | syn::Type::BareFn(_)
+            | syn::Type::Never(_) => true,
+            syn::Type::Array(array) => self.is_known_copy(&array.elem),
+            syn::Type::Tuple(tuple) => tuple.elems.iter().all(|ty| self.is_known_copy(ty)),
+            syn::Type::Paren(paren) => self.is_known_copy(&paren.elem),
+            syn::Type::Group(group) => self.is_known_copy(&group.elem),
+            syn::Type::Path(path) => {
+                if let Some(ident) = path.path.get_ident() {
+                    if PRIMITIVES.iter().any(|p| ident == p)
+                        || self
+                            .generics
+                            .type_params()
+                            .any(|p| &p.ident == ident && p.bounds.iter().any(is_copy_bound))
+                    {
+                        return true;
+                    }
+                }
+                let ty = ty.dump().to_string();
+                self.generics.where_clause.iter().any(|w| {
+                    w.predicates.iter().any(|predicate| {
+                        let WherePredicate::Type(p) = predicate else {
+                            return false;
+                        };
+                        p.bounded_ty.dump().to_string() == ty && p.bounds.iter().any(is_copy_bound)
+                    })
+                })
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A payload of type `T` or `[T; N]` for a replaceable type parameter `T`.
+#[derive(Clone, Copy)]
+struct Rebindable<'a> {
+    param: &'a Ident,
+    len: Option<&'a syn::Expr>,
+}
+
+impl Rebindable<'_> {
+    /// Whether the payload is `T` itself, so `Type<U>` carries exactly `U`.
+    fn is_bare(&self) -> bool {
+        self.len.is_none()
+    }
+
+    /// The payload type with `T` replaced by `elem`.
+    fn payload(&self, elem: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self.len {
+            Some(len) => quote!([#elem; #len]),
+            None => elem,
+        }
+    }
+
+    /// A closure applying `f` to the element(s) of a payload.
+    fn each(&self, f: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self.len {
+            Some(_) => quote!(|v| v.map(#f)),
+            None => f,
+        }
+    }
+}
+
+/// Generated-code fragments to bind, read and rebuild a variant's payload.
+struct Payload<'a> {
+    typ: &'a Ident,
+    variant: &'a Ident,
+    fields: &'a syn::Fields,
+}
+
+impl Payload<'_> {
+    /// Whether the payload is stored as a single field, so it can be borrowed
+    /// as a whole.
+    fn is_single(&self) -> bool {
+        self.fields.len() == 1
+    }
+
+    fn bindings(&self, name: &str) -> Vec<Ident> {
+        if self.is_single() {
+            vec![Ident::new(name, proc_macro2::Span::call_site())]
+        } else {
+            (0..self.fields.len())
+                .map(|i| Ident::new(&format!("{name}_{i}"), proc_macro2::Span::call_site()))
+                .collect()
+        }
+    }
+
+    /// The payload type; a tuple of the field types for multi-field variants.
+    fn ty(&self) -> proc_macro2::TokenStream {
+        let types = self.fields.iter().map(|f| &f.ty);
+        if self.is_single() {
+            quote!(#(#types)*)
+        } else {
+            quote!((#(#types),*))
+        }
+    }
+
+    /// The type of the borrowed payload; a tuple of references for
+    /// multi-field variants.
+    fn ref_ty(&self, mutability: Option<syn::token::Mut>) -> proc_macro2::TokenStream {
+        let types = self.fields.iter().map(|f| &f.ty);
+        if self.is_single() {
+            quote!(#(&#mutability #types)*)
+        } else {
+            quote!((#(&#mutability #types),*))
+        }
+    }
+
+    /// A pattern binding the payload as `name`; as an expression it rebuilds
+    /// the variant from those same bindings.
+    fn pat(&self, name: &str) -> proc_macro2::TokenStream {
+        let Payload { typ, variant, .. } = self;
+        let bindings = self.bindings(name);
+        match self.fields {
+            syn::Fields::Named(fields) => {
+                let names = fields.named.iter().map(|f| &f.ident);
+                quote!(#typ::#variant { #(#names: #bindings),* })
+            }
+            _ => quote!(#typ::#variant(#(#bindings),*)),
+        }
+    }
+
+    /// The payload read back from the bindings of [`Payload::pat`].
+    fn value(&self, name: &str) -> proc_macro2::TokenStream {
+        let bindings = self.bindings(name);
+        if self.is_single() {
+            quote!(#(#bindings)*)
+        } else {
+            quote!((#(#bindings),*))
+        }
+    }
+
+    /// The variant rebuilt from a payload local named `name`.
+    fn construct(&self, name: &str) -> proc_macro2::TokenStream {
+        if self.is_single() {
+            return self.pat(name);
+        }
+        let Payload { typ, variant, .. } = self;
+        let name = Ident::new(name, proc_macro2::Span::call_site());
+        let indices = (0..self.fields.len()).map(syn::Index::from);
+        quote!(#typ::#variant(#(#name.#indices),*))
+    }
+}
+
+/// The referenced type of a `&'a T` or `&'a mut T` payload.
+fn reference_payload(inner: &syn::Fields) -> Option<&syn::Type> {
+    match inner.iter().collect::<Vec<_>>().as_slice() {
+        [
+            Field {
+                ty: syn::Type::Reference(reference),
+                ..
+            },
+        ] => Some(&reference.elem),
+        _ => None,
+    }
+}
+
+/// `tokens` with each parameter of `replacements` replaced.
+fn replace_params(
+    tokens: proc_macro2::TokenStream,
+    replacements: &[(&Ident, proc_macro2::TokenStream)],
+) -> proc_macro2::TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            proc_macro2::TokenTree::Ident(i) => {
+                match replacements.iter().find(|(param, _)| *param == &i) {
+                    Some((_, with)) => with.clone(),
+                    None => i.dump(),
+                }
+            }
+            proc_macro2::TokenTree::Group(g) => {
+                let mut group = proc_macro2::Group::new(
+                    g.delimiter(),
+                    replace_params(g.stream(), replacements),
+                );
+                group.set_span(g.span());
+                group.dump()
+            }
+            token => token.dump(),
+        })
+        .collect()
+}
+
+/// Whether `tokens` contain a `T::Item` path for the parameter `ident`.
+fn shorthand_projection(tokens: proc_macro2::TokenStream, ident: &Ident) -> bool {
+    let tokens: Vec<_> = tokens.into_iter().collect();
+    tokens.iter().enumerate().any(|(i, token)| match token {
+        proc_macro2::TokenTree::Ident(param) if param == ident => matches!(
+            &tokens[i + 1..],
+            [
+                proc_macro2::TokenTree::Punct(a),
+                proc_macro2::TokenTree::Punct(b),
+                proc_macro2::TokenTree::Ident(_),
+                ..,
+            ] if a.as_char() == ':' && b.as_char() == ':'
+        ),
+        proc_macro2::TokenTree::Group(g) => shorthand_projection(g.stream(), ident),
+        _ => false,
+    })
+}
+
+fn mentions(tokens: proc_macro2::TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(i) => &i == ident,
+        proc_macro2::TokenTree::Group(g) => mentions(g.stream(), ident),
+        _ => false,
+    })
 }
 
 struct LikeData {
     name: String,
+    attr: &'static str,
+    roles: (&'static str, &'static str),
+    /// Methods the rest of the generated code is built on.
+    core: &'static [&'static str],
     fields: (VariantFieldsType, VariantFieldsType),
 }
 
+/// Container attributes, e.g. `#[option_like(skip(clone, default))]`.
+#[derive(Default)]
+struct ContainerAttrs {
+    filter: Option<ItemFilter>,
+    vis: Option<Visibility>,
+    krate: Option<syn::Path>,
+    aliases: bool,
+    /// `#[result_like(try)]`. Opting in per type keeps the `nightly` feature
+    /// additive: enums in crates without `#![feature(try_trait_v2)]` stay
+    /// untouched when another crate enables it.
+    try_trait: bool,
+}
+
+impl ContainerAttrs {
+    fn parse(like: &LikeData, input: &DeriveInput) -> syn::Result<Self> {
+        let mut attrs = ContainerAttrs::default();
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident(like.attr)) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("aliases") {
+                    attrs.aliases = true;
+                    return Ok(());
+                }
+                if meta.path.is_ident("try") {
+                    if like.attr == "bool_like" {
+                        return Err(
+                            meta.error("`try` is only supported by `OptionLike` and `ResultLike`")
+                        );
+                    }
+                    if !cfg!(feature = "nightly") {
+                        return Err(
+                            meta.error("`try` needs the `nightly` feature of `result-like`")
+                        );
+                    }
+                    attrs.try_trait = true;
+                    return Ok(());
+                }
+                if meta.path.is_ident("crate") {
+                    attrs.krate = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+                    return Ok(());
+                }
+                if meta.path.is_ident("vis") {
+                    let lit: syn::LitStr = meta.value()?.parse()?;
+                    let vis = lit.parse().map_err(|_| {
+                        syn::Error::new_spanned(&lit, "expected a visibility such as `pub(crate)`")
+                    })?;
+                    attrs.vis = Some(vis);
+                    return Ok(());
+                }
+                let only = if meta.path.is_ident("skip") {
+                    false
+                } else if meta.path.is_ident("only") {
+                    true
+                } else {
+                    return Err(meta.error(format!("unknown `{}` attribute", like.attr)));
+                };
+                if attrs.filter.is_some() {
+                    return Err(meta.error("only one `skip` or `only` list is allowed"));
+                }
+                let mut names = Vec::new();
+                meta.parse_nested_meta(|item| {
+                    names.push(item.path.require_ident()?.clone());
+                    Ok(())
+                })?;
+                attrs.filter = Some(ItemFilter { only, names });
+                Ok(())
+            })?;
+        }
+        Ok(attrs)
+    }
+}
+
+/// The `skip(..)` or `only(..)` list. Inherent methods are named by the method
+/// and trait impls by the snake-cased trait, e.g. `into_iter` or `option_like`.
+struct ItemFilter {
+    only: bool,
+    names: Vec<Ident>,
+}
+
+impl ItemFilter {
+    fn apply(&self, like: &LikeData, file: &mut syn::File) -> syn::Result<()> {
+        let generated: Vec<String> = file
+            .items
+            .iter()
+            .flat_map(|item| match item {
+                syn::Item::Impl(item_impl) => match trait_key(item_impl) {
+                    Some(key) => vec![key],
+                    None => item_impl
+                        .items
+                        .iter()
+                        .filter_map(method_name)
+                        .map(|name| name.to_string())
+                        .collect(),
+                },
+                _ => Vec::new(),
+            })
+            .collect();
+
+        let mut errors = self.names.iter().filter_map(|name| {
+            if !generated.iter().any(|key| name == key) {
+                Some(syn::Error::new_spanned(
+                    name,
+                    format!("`{}` doesn't generate `{name}` for this enum", like.name),
+                ))
+            } else if !self.only && like.core.iter().any(|key| name == key) {
+                Some(syn::Error::new_spanned(
+                    name,
+                    format!("`{name}` can't be skipped; the other generated items use it"),
+                ))
+            } else {
+                None
+            }
+        });
+        if let Some(mut error) = errors.next() {
+            error.extend(errors);
+            return Err(error);
+        }
+
+        let keep = |key: &str| {
+            like.core.contains(&key) || self.names.iter().any(|name| name == key) == self.only
+        };
+        file.items.retain_mut(|item| {
+            let syn::Item::Impl(item_impl) = item else {
+                return true;
+            };
+            if let Some(key) = trait_key(item_impl) {
+                return keep(&key);
+            }
+            item_impl
+                .items
+                .retain(|item| method_name(item).is_none_or(|name| keep(&name.to_string())));
+            !item_impl.items.is_empty()
+        });
+        Ok(())
+    }
+}
+
+fn trait_key(item_impl: &syn::ItemImpl) -> Option<String> {
+    let (_, path, _) = item_impl.trait_.as_ref()?;
+    let name = path.segments.last()?.ident.to_string();
+    if name == "IntoIterator" {
+        return Some("into_iter".to_owned());
+    }
+    Some(snake_case(&name))
+}
+
+/// `NotFound` is `not_found`; an acronym is one word, so `HTTPOn` is `http_on`.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let ends_acronym =
+                prev.is_uppercase() && chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || ends_acronym {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+fn method_name(item: &syn::ImplItem) -> Option<&Ident> {
+    match item {
+        syn::ImplItem::Fn(method) => Some(&method.sig.ident),
+        _ => None,
+    }
+}
+
+/// The role a variant claims with e.g. `#[option_like(some)]`: `Some(true)` for
+/// the primary role, `Some(false)` for the secondary one.
+fn variant_role(like: &LikeData, variant: &Variant) -> syn::Result<Option<bool>> {
+    let mut role = None;
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident(like.attr))
+    {
+        attr.parse_nested_meta(|meta| {
+            let claimed = if meta.path.is_ident(like.roles.0) {
+                true
+            } else if meta.path.is_ident(like.roles.1) {
+                false
+            } else {
+                return Err(
+                    meta.error(format!("expected `{}` or `{}`", like.roles.0, like.roles.1))
+                );
+            };
+            if role.is_some_and(|r| r != claimed) {
+                return Err(meta.error(format!(
+                    "a variant can't be both `{}` and `{}`",
+                    like.roles.0, like.roles.1
+                )));
+            }
+            role = Some(claimed);
+            Ok(())
+        })?;
+    }
+    Ok(role)
+}
+
 fn expand(
     input: &DeriveInput,
     like_trait: impl LikeTrait,
-    data: &DataEnum,
-) -> proc_macro::TokenStream {
+) -> syn::Result<proc_macro2::TokenStream> {
     let typ = &input.ident;
     let like = like_trait.data();
 
-    assert_eq!(
-        data.variants.len(),
-        2,
-        "{} expects 2 variants but {} variant(s) given",
-        &like.name,
-        data.variants.len()
-    );
-
-    let (primary_variant, secondary_variant) = {
-        let mut iter = data.variants.iter();
-        (iter.next().unwrap(), iter.next().unwrap())
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                typ,
+                format!("`{}` can be applied only on enums", like.name),
+            ));
+        }
     };
 
-    assert!(like.fields.0.matches(&primary_variant.fields));
-    assert!(like.fields.1.matches(&secondary_variant.fields));
-
-    let primary = &primary_variant.ident;
-    let secondary = &secondary_variant.ident;
+    let attrs = ContainerAttrs::parse(&like, input)?;
+
+    if data.variants.len() != 2 {
+        let span_target = match data.variants.iter().nth(2) {
+            Some(extra) => extra.dump(),
+            None => typ.dump(),
+        };
+        return Err(syn::Error::new_spanned(
+            span_target,
+            format!(
+                "`{}` expects 2 variants but {} variant(s) given",
+                like.name,
+                data.variants.len()
+            ),
+        ));
+    }
 
-    let primary_inner = match &primary_variant.fields {
-        syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => Some(unnamed),
-        _ => None,
+    let (first, second) = (&data.variants[0], &data.variants[1]);
+    let swapped = match (variant_role(&like, first)?, variant_role(&like, second)?) {
+        (Some(a), Some(b)) if a == b => {
+            let role = if a { like.roles.0 } else { like.roles.1 };
+            return Err(syn::Error::new_spanned(
+                &second.ident,
+                format!("`{role}` is already given to `{}`", first.ident),
+            ));
+        }
+        (Some(a), _) => !a,
+        (None, Some(b)) => b,
+        (None, None) => false,
     };
-    let secondary_inner = match &secondary_variant.fields {
-        syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => Some(unnamed),
-        _ => None,
+    let (primary_variant, secondary_variant, positions) = if swapped {
+        (second, first, ("second", "first"))
+    } else {
+        (first, second, ("first", "second"))
     };
 
+    let checks = [
+        like.fields
+            .0
+            .check(&like.name, positions.0, primary_variant),
+        like.fields
+            .1
+            .check(&like.name, positions.1, secondary_variant),
+    ];
+    let mut errors = checks.into_iter().filter_map(Result::err);
+    if let Some(mut error) = errors.next() {
+        error.extend(errors);
+        return Err(error);
+    }
+
+    let primary = &primary_variant.ident;
+    let secondary = &secondary_variant.ident;
+
     let like_impl = like_trait.quote_impl(ImplArgs {
         typ,
         generics: &input.generics,
         primary,
         secondary,
-        primary_inner,
-        secondary_inner,
-    });
+        primary_fields: &primary_variant.fields,
+        secondary_fields: &secondary_variant.fields,
+        krate: attrs
+            .krate
+            .as_ref()
+            .unwrap_or(&syn::parse_quote!(::result_like)),
+        aliases: attrs.aliases,
+        try_trait: attrs.try_trait,
+    })?;
+
+    let mut file: syn::File = syn::parse2(like_impl.into())?;
+    if let Some(filter) = &attrs.filter {
+        filter.apply(&like, &mut file)?;
+    }
+
+    // Inherent methods are as visible as the enum unless told otherwise.
+    // Only variant-named aliases can repeat a method name, e.g. `is_some` for
+    // a `Some` variant or `map_err` for an `Err` one; they come last, so the
+    // error points at the variant.
+    let vis = attrs.vis.as_ref().unwrap_or(&input.vis);
+    let mut defined = Vec::new();
+    let mut collisions = Vec::new();
+    for item in &mut file.items {
+        let syn::Item::Impl(item_impl) = item else {
+            continue;
+        };
+        if item_impl.trait_.is_some() {
+            continue;
+        }
+        let self_ty = item_impl.self_ty.dump().to_string();
+        for name in item_impl.items.iter().filter_map(method_name) {
+            let key = (self_ty.clone(), name.to_string());
+            if defined.contains(&key) {
+                collisions.push(syn::Error::new(
+                    name.span(),
+                    format!("`aliases` would generate `{name}` twice; rename the variant"),
+                ));
+            }
+            defined.push(key);
+        }
+        for method in &mut item_impl.items {
+            if let syn::ImplItem::Fn(method) = method {
+                method.vis = vis.clone();
+            }
+        }
+        if !matches!(vis, Visibility::Public(_)) {
+            item_impl.attrs.push(syn::parse_quote!(#[allow(dead_code)]));
+        }
+    }
+
+    let mut collisions = collisions.into_iter();
+    if let Some(mut error) = collisions.next() {
+        error.extend(collisions);
+        return Err(error);
+    }
 
-    like_impl.into()
+    Ok(file.dump())
 }
 
 struct BoolLike;
@@ -161,25 +850,33 @@ impl LikeTrait for BoolLike {
     fn data(&self) -> LikeData {
         LikeData {
             name: "BoolLike".to_owned(),
+            attr: "bool_like",
+            roles: ("true", "false"),
+            core: &["to_bool", "from_bool"],
             fields: (VariantFieldsType::Unit, VariantFieldsType::Unit),
         }
     }
 
-    fn quote_impl(&self, args: ImplArgs) -> Quote {
+    fn quote_impl(&self, args: ImplArgs) -> syn::Result<Quote> {
         let ImplArgs {
             typ,
             primary,
             secondary,
             ..
         } = args;
-        Quote::new_call_site().quote_with(smart_quote!(
+        let (impl_generics, ty_generics, where_clause, _) = args.split_for_impl();
+        let mut bool_impl = Quote::new_call_site().quote_with(smart_quote!(
             Vars {
+                result_like: args.krate,
                 Type: &typ,
+                impl_generics: &impl_generics,
+                ty_generics: &ty_generics,
+                where_clause: &where_clause,
                 Primary: primary,
                 Secondary: secondary,
             },
             {
-                impl Type {
+                impl impl_generics Type ty_generics where_clause {
                     pub const fn to_bool(self) -> bool {
                         match self {
                             Type::Primary => true,
@@ -193,28 +890,78 @@ impl LikeTrait for BoolLike {
                             Type::Secondary
                         }
                     }
-                    pub fn then_some<T>(self, t: T) -> Option<T> {
+                    pub const fn is_true(&self) -> bool {
+                        ::core::matches!(self, Type::Primary)
+                    }
+                    pub const fn is_false(&self) -> bool {
+                        ::core::matches!(self, Type::Secondary)
+                    }
+                    pub fn then_some<T>(self, t: T) -> ::core::option::Option<T> {
                         self.to_bool().then_some(t)
                     }
-                    pub fn then<T, F>(self, f: F) -> Option<T>
+                    pub fn then<T, F>(self, f: F) -> ::core::option::Option<T>
                     where
-                        F: FnOnce() -> T,
+                        F: ::core::ops::FnOnce() -> T,
                     {
                         self.to_bool().then(f)
                     }
                 }
-                impl From<bool> for Type {
+                impl impl_generics result_like::BoolLike for Type ty_generics where_clause {
+                    const TRUE: Self = Type::Primary;
+                    const FALSE: Self = Type::Secondary;
+
+                    #[inline]
+                    fn to_bool(&self) -> bool {
+                        ::core::matches!(self, Type::Primary)
+                    }
+
+                    #[inline]
+                    fn from_bool(value: bool) -> Self {
+                        Type::from_bool(value)
+                    }
+                }
+                impl impl_generics ::core::convert::From<bool> for Type ty_generics where_clause {
                     fn from(value: bool) -> Self {
                         Self::from_bool(value)
                     }
                 }
-                impl From<Type> for bool {
-                    fn from(value: Type) -> Self {
+                impl impl_generics ::core::convert::From<Type ty_generics> for bool where_clause {
+                    fn from(value: Type ty_generics) -> Self {
                         value.to_bool()
                     }
                 }
             }
-        ))
+        ));
+        if args.aliases {
+            let is_primary = args.alias(primary, "is_{}")?;
+            let is_secondary = args.alias(secondary, "is_{}")?;
+            bool_impl = bool_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_clause: &where_clause,
+                    Primary: primary,
+                    Secondary: secondary,
+                    IsPrimary: is_primary,
+                    IsSecondary: is_secondary,
+                },
+                {
+                    impl impl_generics Type ty_generics where_clause {
+                        #[inline]
+                        pub const fn IsPrimary(&self) -> bool {
+                            ::core::matches!(self, Type::Primary)
+                        }
+
+                        #[inline]
+                        pub const fn IsSecondary(&self) -> bool {
+                            ::core::matches!(self, Type::Secondary)
+                        }
+                    }
+                }
+            ));
+        }
+        Ok(bool_impl)
     }
 }
 
@@ -224,114 +971,200 @@ impl LikeTrait for OptionLike {
     fn data(&self) -> LikeData {
         LikeData {
             name: "OptionLike".to_owned(),
-            fields: (VariantFieldsType::Unnamed, VariantFieldsType::Unit),
+            attr: "option_like",
+            roles: ("some", "none"),
+            core: &["from_option", "into_option", "as_option", "as_option_mut"],
+            fields: (VariantFieldsType::Payload, VariantFieldsType::Unit),
         }
     }
 
-    fn quote_impl(&self, args: ImplArgs) -> Quote {
+    fn quote_impl(&self, args: ImplArgs) -> syn::Result<Quote> {
         let ImplArgs {
             typ,
             primary,
             secondary,
-            primary_inner,
+            primary_fields,
             ..
         } = args;
-        let primary_inner = primary_inner.expect("primary_inner always exists for OptionLike");
+        let some = args.primary_payload();
+        let primary_inner = some.ty();
         let (impl_generics, ty_generics, where_clause, where_predicates) = args.split_for_impl();
+        let some_param = args.rebindable_param(primary_fields, &[]);
+        // `Type<_Other>` and the bounds it needs; `Rebind` can't carry any.
+        let (map_type, map_bounds) = match some_param.filter(Rebindable::is_bare) {
+            Some(param) => {
+                let generics = args.rebind_generics(&[(param.param, quote!(_Other))]);
+                (
+                    quote!(#typ #generics),
+                    args.rebound_predicates(&[(param.param, quote!(_Other))]),
+                )
+            }
+            None => (quote!(::core::option::Option<_Other>), quote!()),
+        };
+        let rebind = if map_bounds.is_empty() {
+            map_type.clone()
+        } else {
+            quote!(::core::option::Option<_Other>)
+        };
         let mut option_impl = Quote::new_call_site().quote_with(smart_quote!(
             Vars {
+                SomePat: some.pat("v"),
+                SomeVal: some.value("v"),
+                SomeNew: some.construct("v"),
+                SomeRef: some.ref_ty(None),
+                SomeMut: some.ref_ty(Some(Default::default())),
                 Type: &typ,
                 impl_generics: &impl_generics,
                 ty_generics: &ty_generics,
                 where_clause: &where_clause,
                 Primary: primary,
                 Secondary: secondary,
-                PrimaryValue: primary_inner,
+                PrimaryValue: &primary_inner,
+                ConstIfCopy: args.const_if_copy(&[primary_fields]),
+                // Splitting a tuple apart isn't `const` unless it's `Copy`.
+                ConstReplace: args
+                    .const_if_copy(&[primary_fields])
+                    .or(some.is_single().then(Default::default)),
             },
             {
-                impl impl_generics result_like::OptionLike for Type ty_generics where_clause {
-                    type SomeType = PrimaryValue;
-                }
                 impl impl_generics Type ty_generics where_clause {
                     #[inline]
-                    pub fn from_option(option: Option<PrimaryValue>) -> Self {
+                    pub ConstIfCopy fn from_option(option: ::core::option::Option<PrimaryValue>) -> Self {
                         match option {
-                            Some(v) => Type::Primary(v),
-                            None => Type::Secondary,
+                            ::core::option::Option::Some(v) => SomeNew,
+                            ::core::option::Option::None => Type::Secondary,
                         }
                     }
 
                     #[inline]
-                    pub fn into_option(self) -> Option<PrimaryValue> {
+                    pub ConstIfCopy fn into_option(self) -> ::core::option::Option<PrimaryValue> {
                         match self {
-                            Type::Primary(v) => Some(v),
-                            Type::Secondary => None,
+                            SomePat => ::core::option::Option::Some(SomeVal),
+                            Type::Secondary => ::core::option::Option::None,
                         }
                     }
 
                     #[inline]
-                    pub fn as_option(&self) -> Option<&PrimaryValue> {
+                    pub const fn as_option(&self) -> ::core::option::Option<SomeRef> {
                         match self {
-                            Type::Primary(v) => Some(v),
-                            Type::Secondary => None,
+                            SomePat => ::core::option::Option::Some(SomeVal),
+                            Type::Secondary => ::core::option::Option::None,
                         }
                     }
 
                     #[inline]
-                    pub fn as_option_mut(&mut self) -> Option<&mut PrimaryValue> {
+                    pub const fn as_option_mut(&mut self) -> ::core::option::Option<SomeMut> {
                         match self {
-                            Type::Primary(v) => Some(v),
-                            Type::Secondary => None,
+                            SomePat => ::core::option::Option::Some(SomeVal),
+                            Type::Secondary => ::core::option::Option::None,
                         }
                     }
 
                     #[inline]
-                    pub fn expect(self, msg: &str) -> PrimaryValue where {
-                        self.into_option().expect(msg)
+                    pub const fn is_some(&self) -> bool {
+                        ::core::matches!(self, Type::Primary { .. })
                     }
 
                     #[inline]
-                    pub fn unwrap(self) -> PrimaryValue {
-                        self.into_option().unwrap()
+                    pub const fn is_none(&self) -> bool {
+                        ::core::matches!(self, Type::Secondary)
+                    }
+
+                    #[inline]
+                    pub fn is_some_and<_Function: ::core::ops::FnOnce(PrimaryValue) -> bool>(self, f: _Function) -> bool {
+                        self.into_option().is_some_and(f)
+                    }
+
+                    #[inline]
+                    pub fn is_none_or<_Function: ::core::ops::FnOnce(PrimaryValue) -> bool>(self, f: _Function) -> bool {
+                        self.into_option().is_none_or(f)
+                    }
+
+                    #[inline]
+                    pub ConstIfCopy fn expect(self, msg: &str) -> PrimaryValue {
+                        match self {
+                            SomePat => SomeVal,
+                            Type::Secondary => ::core::panic!("{}", msg),
+                        }
+                    }
+
+                    #[inline]
+                    pub ConstIfCopy fn unwrap(self) -> PrimaryValue {
+                        match self {
+                            SomePat => SomeVal,
+                            Type::Secondary => ::core::panic!("called `Option::unwrap()` on a `None` value"),
+                        }
                     }
 
                     #[inline]
-                    pub fn unwrap_or(self, default: PrimaryValue) -> PrimaryValue {
-                        self.into_option().unwrap_or(default)
+                    pub ConstIfCopy fn unwrap_or(self, default: PrimaryValue) -> PrimaryValue {
+                        match self {
+                            SomePat => SomeVal,
+                            Type::Secondary => default,
+                        }
                     }
 
                     #[inline]
-                    pub fn unwrap_or_else<_Function: FnOnce() -> PrimaryValue>(self, f: _Function) -> PrimaryValue {
+                    pub fn unwrap_or_else<_Function: ::core::ops::FnOnce() -> PrimaryValue>(self, f: _Function) -> PrimaryValue {
                         self.into_option().unwrap_or_else(f)
                     }
 
                     #[inline]
-                    pub fn ok_or<_Error>(self, err: _Error) -> Result<PrimaryValue, _Error> {
+                    pub fn unwrap_or_default(self) -> PrimaryValue where for<'__r> PrimaryValue: ::core::default::Default {
+                        self.into_option().unwrap_or_default()
+                    }
+
+                    /// # Safety
+                    ///
+                    /// Calling this method on the none variant is undefined behavior.
+                    #[inline]
+                    pub ConstIfCopy unsafe fn unwrap_unchecked(self) -> PrimaryValue {
+                        match self {
+                            SomePat => SomeVal,
+                            Type::Secondary => unsafe { ::core::hint::unreachable_unchecked() },
+                        }
+                    }
+
+                    #[inline]
+                    pub fn ok_or<_Error>(self, err: _Error) -> ::core::result::Result<PrimaryValue, _Error> {
                         self.into_option().ok_or(err)
                     }
 
                     #[inline]
-                    pub fn ok_or_else<_Error, _Function: FnOnce() -> _Error>(self, err: _Function) -> Result<PrimaryValue, _Error> {
+                    pub fn ok_or_else<_Error, _Function: ::core::ops::FnOnce() -> _Error>(self, err: _Function) -> ::core::result::Result<PrimaryValue, _Error> {
                         self.into_option().ok_or_else(err)
                     }
 
                     #[inline]
-                    pub fn filter<P: FnOnce(&PrimaryValue) -> bool>(self, predicate: P) -> Self {
+                    pub fn iter(&self) -> ::core::option::IntoIter<SomeRef> {
+                        self.as_option().into_iter()
+                    }
+
+                    #[inline]
+                    pub fn iter_mut(&mut self) -> ::core::option::IntoIter<SomeMut> {
+                        self.as_option_mut().into_iter()
+                    }
+
+                    #[inline]
+                    pub fn filter<P: ::core::ops::FnOnce(&PrimaryValue) -> bool>(self, predicate: P) -> Self {
                         Self::from_option(self.into_option().filter(predicate))
                     }
 
                     #[inline]
-                    pub fn or(self, optb: Self) -> Self {
-                        Self::from_option(self.into_option().or(optb.into_option()))
+                    pub ConstIfCopy fn or(self, optb: Self) -> Self {
+                        match self {
+                            some @ Type::Primary { .. } => some,
+                            Type::Secondary => optb,
+                        }
                     }
 
                     #[inline]
-                    pub fn or_else<_Function: FnOnce() -> Self>(self, f: _Function) -> Self {
+                    pub fn or_else<_Function: ::core::ops::FnOnce() -> Self>(self, f: _Function) -> Self {
                         Self::from_option(self.into_option().or_else(|| f().into_option()))
                     }
 
                     #[inline]
-                    pub fn map_or<_Other, _Function: FnOnce(PrimaryValue) -> _Other>(
+                    pub fn map_or<_Other, _Function: ::core::ops::FnOnce(PrimaryValue) -> _Other>(
                         self,
                         default: _Other,
                         f: _Function,
@@ -340,173 +1173,433 @@ impl LikeTrait for OptionLike {
                     }
 
                     #[inline]
-                    pub fn xor(self, optb: Self) -> Self {
-                        Self::from_option(self.into_option().xor(optb.into_option()))
+                    pub fn map_or_else<_Other, _Default: ::core::ops::FnOnce() -> _Other, _Function: ::core::ops::FnOnce(PrimaryValue) -> _Other>(
+                        self,
+                        default: _Default,
+                        f: _Function,
+                    ) -> _Other {
+                        self.into_option().map_or_else(default, f)
                     }
 
                     #[inline]
-                    pub fn get_or_insert(&mut self, v: PrimaryValue) -> &mut PrimaryValue {
-                        self.get_or_insert_with(|| v)
+                    pub fn update<_Function: ::core::ops::FnOnce(PrimaryValue) -> PrimaryValue>(self, f: _Function) -> Self {
+                        match self {
+                            SomePat => {
+                                let v = f(SomeVal);
+                                SomeNew
+                            }
+                            Type::Secondary => Type::Secondary,
+                        }
                     }
 
                     #[inline]
-                    pub fn get_or_insert_with<_Function: FnOnce() -> PrimaryValue>(&mut self, f: _Function) -> &mut PrimaryValue {
-                        if let Type::Secondary = *self {
-                            *self = Type::Primary(f());
-                        }
-
+                    pub fn inspect<_Function: ::core::ops::FnOnce(&PrimaryValue)>(self, f: _Function) -> Self {
                         match self {
-                            Type::Primary(v) => v,
-                            Type::Secondary => unsafe { core::hint::unreachable_unchecked() },
+                            SomePat => {
+                                let v = SomeVal;
+                                f(&v);
+                                SomeNew
+                            }
+                            Type::Secondary => Type::Secondary,
                         }
                     }
 
                     #[inline]
-                    pub fn replace(&mut self, value: PrimaryValue) -> Self {
-                        core::mem::replace(self, Type::Primary(value))
+                    pub ConstIfCopy fn xor(self, optb: Self) -> Self {
+                        match (self, optb) {
+                            (some @ Type::Primary { .. }, Type::Secondary)
+                            | (Type::Secondary, some @ Type::Primary { .. }) => some,
+                            _ => Type::Secondary,
+                        }
                     }
-                }
 
-                impl impl_generics From<Option<PrimaryValue>> for Type ty_generics where_clause {
-                    fn from(value: Option<PrimaryValue>) -> Self {
-                        Self::from_option(value)
-                    }
+                    #[inline]
+                    pub ConstIfCopy fn insert(&mut self, v: PrimaryValue) -> SomeMut {
+                        *self = SomeNew;
+
+                        match self {
+                            SomePat => SomeVal,
+                            Type::Secondary => unsafe { ::core::hint::unreachable_unchecked() },
+                        }
+                    }
+
+                    #[inline]
+                    pub ConstIfCopy fn get_or_insert(&mut self, v: PrimaryValue) -> SomeMut {
+                        if let Type::Secondary = *self {
+                            *self = SomeNew;
+                        }
+
+                        match self {
+                            SomePat => SomeVal,
+                            Type::Secondary => unsafe { ::core::hint::unreachable_unchecked() },
+                        }
+                    }
+
+                    #[inline]
+                    pub fn get_or_insert_default(&mut self) -> SomeMut where for<'__r> PrimaryValue: ::core::default::Default {
+                        if let Type::Secondary = *self {
+                            let v: PrimaryValue = ::core::default::Default::default();
+                            *self = SomeNew;
+                        }
+
+                        match self {
+                            SomePat => SomeVal,
+                            Type::Secondary => unsafe { ::core::hint::unreachable_unchecked() },
+                        }
+                    }
+
+                    #[inline]
+                    pub fn get_or_insert_with<_Function: ::core::ops::FnOnce() -> PrimaryValue>(&mut self, f: _Function) -> SomeMut {
+                        if let Type::Secondary = *self {
+                            let v = f();
+                            *self = SomeNew;
+                        }
+
+                        match self {
+                            SomePat => SomeVal,
+                            Type::Secondary => unsafe { ::core::hint::unreachable_unchecked() },
+                        }
+                    }
+
+                    #[inline]
+                    pub const fn take(&mut self) -> Self {
+                        ::core::mem::replace(self, Type::Secondary)
+                    }
+
+                    #[inline]
+                    pub fn take_if<_Predicate: ::core::ops::FnOnce(SomeMut) -> bool>(&mut self, predicate: _Predicate) -> Self {
+                        if self.as_option_mut().is_some_and(predicate) {
+                            ::core::mem::replace(self, Type::Secondary)
+                        } else {
+                            Type::Secondary
+                        }
+                    }
+
+                    #[inline]
+                    pub ConstReplace fn replace(&mut self, v: PrimaryValue) -> Self {
+                        ::core::mem::replace(self, SomeNew)
+                    }
+                }
+
+                impl impl_generics ::core::convert::From<::core::option::Option<PrimaryValue>> for Type ty_generics where_clause {
+                    fn from(value: ::core::option::Option<PrimaryValue>) -> Self {
+                        Self::from_option(value)
+                    }
                 }
 
-                impl impl_generics From<Type ty_generics> for Option<PrimaryValue> where_clause {
+                impl impl_generics ::core::convert::From<Type ty_generics> for ::core::option::Option<PrimaryValue> where_clause {
                     fn from(value: Type ty_generics) -> Self {
                         value.into_option()
                     }
                 }
 
-                impl impl_generics From<PrimaryValue> for Type ty_generics where_clause {
+                impl impl_generics ::core::convert::From<PrimaryValue> for Type ty_generics where_clause {
                     #[inline]
-                    fn from(value: PrimaryValue) -> Self {
-                        Type::Primary(value)
+                    fn from(v: PrimaryValue) -> Self {
+                        SomeNew
                     }
                 }
 
-                impl impl_generics Default for Type ty_generics {
+                impl impl_generics ::core::default::Default for Type ty_generics where_clause {
                     #[inline]
                     fn default() -> Self {
                         Type::Secondary
                     }
                 }
 
-                impl impl_generics IntoIterator for Type ty_generics where_clause {
+                impl impl_generics ::core::iter::IntoIterator for Type ty_generics where_clause {
                     type Item = PrimaryValue;
-                    type IntoIter = core::option::IntoIter<PrimaryValue>;
+                    type IntoIter = ::core::option::IntoIter<PrimaryValue>;
 
                     #[inline]
-                    fn into_iter(self) -> core::option::IntoIter<PrimaryValue> {
+                    fn into_iter(self) -> ::core::option::IntoIter<PrimaryValue> {
                         self.into_option().into_iter()
                     }
                 }
             }
         ));
-        if !ty_generics.params.is_empty() {
+        // `as_option` can't lend out a tuple that isn't stored anywhere, so
+        // multi-field variants only get the inherent API.
+        if some.is_single() {
             option_impl = option_impl.quote_with(smart_quote!(
                 Vars {
+                    result_like: args.krate,
                     Type: &typ,
                     impl_generics: &impl_generics,
                     ty_generics: &ty_generics,
-                    where_predicates: &where_predicates,
                     where_clause: &where_clause,
-                    Primary: primary,
+                    PrimaryValue: &primary_inner,
+                    RebindType: &rebind,
+                    SomePat: some.pat("v"),
                     Secondary: secondary,
-                    PrimaryValue: primary_inner,
+                    ImplRefG: args.impl_generics_with(impl_generics, quote!('__a)),
                 },
                 {
+                    impl impl_generics result_like::OptionLike for Type ty_generics where_clause {
+                        type SomeType = PrimaryValue;
+                        type Rebind<_Other> = RebindType;
+
+                        #[inline]
+                        fn from_option(option: ::core::option::Option<PrimaryValue>) -> Self {
+                            Type::from_option(option)
+                        }
+
+                        #[inline]
+                        fn into_option(self) -> ::core::option::Option<PrimaryValue> {
+                            Type::into_option(self)
+                        }
+
+                        #[inline]
+                        fn as_option(&self) -> ::core::option::Option<&PrimaryValue> {
+                            Type::as_option(self)
+                        }
+
+                        #[inline]
+                        fn as_option_mut(&mut self) -> ::core::option::Option<&mut PrimaryValue> {
+                            Type::as_option_mut(self)
+                        }
+                    }
+
                     impl impl_generics Type ty_generics where_clause {
                         #[inline]
-                        pub fn as_ref(&self) -> Type<&PrimaryValue> {
+                        pub const fn as_slice(&self) -> &[PrimaryValue] {
                             match self {
-                                Type::Primary(x) => Type::Primary(x),
-                                Type::Secondary => Type::Secondary,
+                                SomePat => ::core::slice::from_ref(v),
+                                Type::Secondary => &[],
                             }
                         }
 
                         #[inline]
-                        pub fn as_mut(&mut self) -> Type<&mut PrimaryValue> {
+                        pub const fn as_mut_slice(&mut self) -> &mut [PrimaryValue] {
                             match self {
-                                Type::Primary(x) => Type::Primary(x),
-                                Type::Secondary => Type::Secondary,
+                                SomePat => ::core::slice::from_mut(v),
+                                Type::Secondary => &mut [],
                             }
                         }
+                    }
+
+                    impl ImplRefG ::core::iter::IntoIterator for &'__a Type ty_generics where_clause {
+                        type Item = &'__a PrimaryValue;
+                        type IntoIter = ::core::option::IntoIter<&'__a PrimaryValue>;
+
+                        #[inline]
+                        fn into_iter(self) -> ::core::option::IntoIter<&'__a PrimaryValue> {
+                            self.as_option().into_iter()
+                        }
+                    }
 
-                        // as_pin_ref
-                        // as_pin_mut
+                    impl ImplRefG ::core::iter::IntoIterator for &'__a mut Type ty_generics where_clause {
+                        type Item = &'__a mut PrimaryValue;
+                        type IntoIter = ::core::option::IntoIter<&'__a mut PrimaryValue>;
 
                         #[inline]
-                        pub fn map<_Other, _Function: FnOnce(PrimaryValue) -> _Other>(self, f: _Function) -> Type<_Other> {
-                            match self {
-                                Type::Primary(x) => Type::Primary(f(x)),
-                                Type::Secondary => Type::Secondary,
-                            }
+                        fn into_iter(self) -> ::core::option::IntoIter<&'__a mut PrimaryValue> {
+                            self.as_option_mut().into_iter()
+                        }
+                    }
+                }
+            ));
+        }
+        if some_param.is_none() {
+            // Without a type parameter to replace, combinators that change the
+            // payload type return a std `Option`, just like `Rebind` does.
+            option_impl = option_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_clause: &where_clause,
+                    PrimaryValue: &primary_inner,
+                    SomeRef: some.ref_ty(None),
+                    SomeMut: some.ref_ty(Some(Default::default())),
+                },
+                {
+                    impl impl_generics Type ty_generics where_clause {
+                        #[inline]
+                        pub const fn as_ref(&self) -> ::core::option::Option<SomeRef> {
+                            self.as_option()
                         }
 
                         #[inline]
-                        pub fn map_or_else<_Other, _Default: FnOnce() -> _Other, _Function: FnOnce(PrimaryValue) -> _Other>(
-                            self,
-                            default: _Default,
-                            f: _Function,
-                        ) -> _Other {
-                            self.into_option().map_or_else(default, f)
+                        pub const fn as_mut(&mut self) -> ::core::option::Option<SomeMut> {
+                            self.as_option_mut()
                         }
 
-                        pub fn iter(&self) -> impl core::iter::Iterator<Item = &PrimaryValue> {
-                            self.as_option().into_iter()
+                        #[inline]
+                        pub fn map<_Other, _Function: ::core::ops::FnOnce(PrimaryValue) -> _Other>(self, f: _Function) -> ::core::option::Option<_Other> {
+                            self.into_option().map(f)
                         }
 
-                        pub fn iter_mut(&mut self) -> impl core::iter::Iterator<Item = &mut PrimaryValue> {
-                            self.as_option_mut().into_iter()
+                        #[inline]
+                        pub fn and<_Other>(self, optb: ::core::option::Option<_Other>) -> ::core::option::Option<_Other> {
+                            self.into_option().and(optb)
+                        }
+
+                        #[inline]
+                        pub fn and_then<_Other, _Function: ::core::ops::FnOnce(PrimaryValue) -> ::core::option::Option<_Other>>(self, f: _Function) -> ::core::option::Option<_Other> {
+                            self.into_option().and_then(f)
                         }
 
                         #[inline]
-                        pub fn and<_Other>(self, optb: Type<_Other>) -> Type<_Other> {
+                        pub fn zip<_Other>(self, other: ::core::option::Option<_Other>) -> ::core::option::Option<(PrimaryValue, _Other)> {
+                            self.into_option().zip(other)
+                        }
+                    }
+                }
+            ));
+            if some.is_single() && reference_payload(primary_fields).is_none() {
+                option_impl = option_impl.quote_with(smart_quote!(
+                    Vars {
+                        Type: &typ,
+                        impl_generics: &impl_generics,
+                        ty_generics: &ty_generics,
+                        where_clause: &where_clause,
+                        PrimaryValue: &primary_inner,
+                        SomePat: some.pat("v"),
+                        Secondary: secondary,
+                    },
+                    {
+                        impl impl_generics Type ty_generics where_clause {
+                            #[inline]
+                            pub const fn copied(&self) -> ::core::option::Option<PrimaryValue> where for<'__r> PrimaryValue: ::core::marker::Copy {
+                                match self {
+                                    SomePat => ::core::option::Option::Some(*v),
+                                    Type::Secondary => ::core::option::Option::None,
+                                }
+                            }
+
+                            #[inline]
+                            pub fn cloned(&self) -> ::core::option::Option<PrimaryValue> where for<'__r> PrimaryValue: ::core::clone::Clone {
+                                self.as_option().cloned()
+                            }
+                        }
+                    }
+                ));
+            }
+        }
+        if let Some(param) = some_param {
+            let rebind = |with| args.rebind_generics(&[(param.param, with)]);
+            let bounds = |with| args.rebound_predicates(&[(param.param, with)]);
+            let elem = param.param;
+            let new = some.construct("v");
+            // `each_ref` and friends aren't `const` on the minimum supported
+            // Rust, so array payloads keep their methods non-const.
+            let const_if_bare: Option<syn::token::Const> = param.is_bare().then(Default::default);
+            let copied_body = |each: proc_macro2::TokenStream| {
+                if param.is_bare() {
+                    let (pat, new) = (some.pat("v"), some.construct("v"));
+                    quote!(match self {
+                        #pat => {
+                            let v = *v;
+                            #new
+                        }
+                        #typ::#secondary => #typ::#secondary,
+                    })
+                } else {
+                    let each = param.each(each);
+                    quote!(#typ::from_option(self.into_option().map(#each)))
+                }
+            };
+            let (as_ref_arm, as_mut_arm) = if param.is_bare() {
+                (new.clone(), new)
+            } else {
+                (
+                    quote!({ let v = v.each_ref(); #new }),
+                    quote!({ let v = v.each_mut(); #new }),
+                )
+            };
+            option_impl = option_impl.quote_with(smart_quote!(
+                Vars {
+                    SomePat: some.pat("v"),
+                    SomeVal: some.value("v"),
+                    SomeNew: some.construct("v"),
+                    SomeFromPat: some.pat("from"),
+                    SomeFromVal: some.value("from"),
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_predicates: &where_predicates,
+                    where_clause: &where_clause,
+                    Primary: primary,
+                    Secondary: secondary,
+                    PrimaryValue: &primary_inner,
+                    Elem: elem,
+                    OtherPayload: param.payload(quote!(_Other)),
+                    OtherG: rebind(quote!(_Other)),
+                    OtherBounds: bounds(quote!(_Other)),
+                    RefSelfG: rebind(quote!(&'__s #elem)),
+                    MutSelfG: rebind(quote!(&'__s mut #elem)),
+                    RefSelfBounds: bounds(quote!(&'__s #elem)),
+                    MutSelfBounds: bounds(quote!(&'__s mut #elem)),
+                    ImplRefG: args.impl_generics_with(impl_generics, quote!('__a)),
+                    RefLifetimeG: rebind(quote!(&'__a #elem)),
+                    MutLifetimeG: rebind(quote!(&'__a mut #elem)),
+                    RefLifetimeBounds: bounds(quote!(&'__a #elem)),
+                    MutLifetimeBounds: bounds(quote!(&'__a mut #elem)),
+                    AsRefArm: as_ref_arm,
+                    AsMutArm: as_mut_arm,
+                    ConstIfBare: &const_if_bare,
+                    CopyRefBody: copied_body(quote!(|&t| t)),
+                    CopyMutBody: copied_body(quote!(|&mut t| t)),
+                    CloneEach: param.each(quote!(|t| <#elem as ::core::clone::Clone>::clone(t))),
+                },
+                {
+                    impl impl_generics Type ty_generics where_clause {
+                        #[inline]
+                        pub ConstIfBare fn as_ref<'__s>(&'__s self) -> Type RefSelfG where RefSelfBounds {
                             match self {
-                                Type::Primary(_) => optb,
+                                SomePat => AsRefArm,
                                 Type::Secondary => Type::Secondary,
                             }
                         }
 
                         #[inline]
-                        pub fn and_then<_Other, _Function: FnOnce(PrimaryValue) -> Type<_Other>>(self, f: _Function) -> Type<_Other> {
+                        pub ConstIfBare fn as_mut<'__s>(&'__s mut self) -> Type MutSelfG where MutSelfBounds {
                             match self {
-                                Type::Primary(x) => f(x),
+                                SomePat => AsMutArm,
                                 Type::Secondary => Type::Secondary,
                             }
                         }
 
-                        pub fn zip<_Other>(self, other: Type<_Other>) -> Type<(PrimaryValue, _Other)> {
-                            Type::from_option(self.into_option().zip(other.into_option()))
+                        #[inline]
+                        pub fn map<_Other, _Function: ::core::ops::FnOnce(PrimaryValue) -> OtherPayload>(self, f: _Function) -> Type OtherG where OtherBounds {
+                            match self {
+                                SomePat => {
+                                    let v = f(SomeVal);
+                                    SomeNew
+                                }
+                                Type::Secondary => Type::Secondary,
+                            }
                         }
 
-                        // pub fn zip_with<_Other, _Function, _Result>(self, other: Type<_Other>, f: _Function) -> Type<_Result>
-                        // where
-                        //     _Function: FnOnce(PrimaryValue, _Other) -> _Result,
-                        // {
-                        //     Type::from_option(self.into_option().zip_with(other.into_option(), f))
-                        // }
-
                         #[inline]
-                        pub fn take(&mut self) -> Self where where_predicates PrimaryValue: Default {
-                            core::mem::take(self)
+                        pub fn and<_Other>(self, optb: Type OtherG) -> Type OtherG where OtherBounds {
+                            match self {
+                                Type::Primary { .. } => optb,
+                                Type::Secondary => Type::Secondary,
+                            }
                         }
 
                         #[inline]
-                        pub fn unwrap_or_default(self) -> PrimaryValue where where_predicates PrimaryValue: Default {
-                            self.into_option().unwrap_or_default()
+                        pub fn and_then<_Other, _Function: ::core::ops::FnOnce(PrimaryValue) -> Type OtherG>(self, f: _Function) -> Type OtherG where OtherBounds {
+                            match self {
+                                SomePat => f(SomeVal),
+                                Type::Secondary => Type::Secondary,
+                            }
                         }
+
                     }
 
-                    impl impl_generics Copy for Type ty_generics where where_predicates PrimaryValue: Copy {}
+                    impl impl_generics ::core::marker::Copy for Type ty_generics where where_predicates PrimaryValue: ::core::marker::Copy {}
 
-                    impl impl_generics Clone for Type ty_generics where where_predicates PrimaryValue: Clone {
+                    impl impl_generics ::core::clone::Clone for Type ty_generics where where_predicates PrimaryValue: ::core::clone::Clone {
                         #[inline]
                         fn clone(&self) -> Self {
                             match self {
-                                Type::Primary(x) => Type::Primary(x.clone()),
+                                SomePat => {
+                                    let v = SomeVal.clone();
+                                    SomeNew
+                                }
                                 Type::Secondary => Type::Secondary,
                             }
                         }
@@ -514,393 +1607,1107 @@ impl LikeTrait for OptionLike {
                         #[inline]
                         fn clone_from(&mut self, source: &Self) {
                             match (self, source) {
-                                (Type::Primary(to), Type::Primary(from)) => to.clone_from(from),
+                                (SomePat, SomeFromPat) => SomeVal.clone_from(SomeFromVal),
                                 (to, from) => *to = from.clone(),
                             }
                         }
                     }
 
-                    impl impl_generics Type<&PrimaryValue> where where_predicates PrimaryValue: Copy {
-                        pub fn copied(self) -> Type<PrimaryValue> {
-                            self.map(|&t| t)
+                    impl ImplRefG Type RefLifetimeG where where_predicates RefLifetimeBounds Elem: ::core::marker::Copy {
+                        pub ConstIfBare fn copied(self) -> Type ty_generics {
+                            CopyRefBody
                         }
                     }
 
-                    impl impl_generics Type<&mut PrimaryValue> where where_predicates PrimaryValue: Copy {
-                        pub fn copied(self) -> Type<PrimaryValue> {
-                            self.map(|&mut t| t)
+                    impl ImplRefG Type MutLifetimeG where where_predicates MutLifetimeBounds Elem: ::core::marker::Copy {
+                        pub ConstIfBare fn copied(self) -> Type ty_generics {
+                            CopyMutBody
                         }
                     }
 
-                    impl impl_generics Type<&PrimaryValue> where where_predicates PrimaryValue: Clone {
-                        pub fn cloned(self) -> Type<PrimaryValue> {
-                            self.map(|t| t.clone())
-                        }
-                    }
-
-                    impl impl_generics Type<&mut PrimaryValue> where where_predicates PrimaryValue: Clone {
-                        pub fn cloned(self) -> Type<PrimaryValue> {
-                            self.map(|t| t.clone())
+                    impl ImplRefG Type RefLifetimeG where where_predicates RefLifetimeBounds Elem: ::core::clone::Clone {
+                        pub fn cloned(self) -> Type ty_generics {
+                            Type::from_option(self.into_option().map(CloneEach))
                         }
                     }
 
-                    // impl<T: fmt::Debug> Type<T>
-                    // expect_none
-                    // unwrap_none
-
-                    impl impl_generics Type<PrimaryValue> where where_predicates PrimaryValue: core::ops::Deref {
-                        pub fn as_deref(&self) -> Type<&PrimaryValue::Target> {
-                            self.as_ref().map(|t| t.deref())
+                    impl ImplRefG Type MutLifetimeG where where_predicates MutLifetimeBounds Elem: ::core::clone::Clone {
+                        pub fn cloned(self) -> Type ty_generics {
+                            Type::from_option(self.into_option().map(CloneEach))
                         }
                     }
 
-                    impl<PrimaryValue: core::ops::DerefMut> Type<PrimaryValue> {
-                        pub fn as_deref_mut(&mut self) -> Type<&mut PrimaryValue::Target> {
-                            self.as_mut().map(|t| t.deref_mut())
+                    impl ImplRefG ::core::convert::From<&'__a Type ty_generics> for Type RefLifetimeG where where_predicates RefLifetimeBounds {
+                        #[inline]
+                        fn from(value: &'__a Type ty_generics) -> Self {
+                            match value {
+                                SomePat => AsRefArm,
+                                Type::Secondary => Type::Secondary,
+                            }
                         }
                     }
 
-                    impl<PrimaryValue, _Error> Type<Result<PrimaryValue, _Error>> {
+                    impl ImplRefG ::core::convert::From<&'__a mut Type ty_generics> for Type MutLifetimeG where where_predicates MutLifetimeBounds {
                         #[inline]
-                        pub fn transpose(self) -> Result<Type<PrimaryValue>, _Error> {
-                            self.into_option()
-                                .transpose()
-                                .map(|op| Type::from_option(op))
+                        fn from(value: &'__a mut Type ty_generics) -> Self {
+                            match value {
+                                SomePat => AsMutArm,
+                                Type::Secondary => Type::Secondary,
+                            }
                         }
                     }
-
-                    // TODO: result-like
-
-                    // impl<'a, PrimaryValue> IntoIterator for &'a Type<PrimaryValue> {
-                    //     type Item = &'a PrimaryValue;
-                    //     type IntoIter = core::option::Iter<'a, PrimaryValue>;
-
-                    //     fn into_iter(self) -> core::option::Iter<'a, PrimaryValue> {
-                    //         self.as_option().iter()
-                    //     }
-                    // }
-
-                    // impl<'a, T> IntoIterator for &'a mut Self {
-                    //     type Item = &'a mut T;
-                    //     type IntoIter = core::option::IterMut<'a, T>;
-
-                    //     fn into_iter(self) -> core::option::IterMut<'a, T> {
-                    //         self.iter_mut()
-                    //     }
-                    // }
-
-                    // impl impl_generics From ty_generics  for Type ty_generics where_clause {
-                    //     fn from(val: PrimaryValue) -> Self {
-                    //         Type::Primary(val)
-                    //     }
-                    // }
-
-                    // impl<'a, T> From<&'a Type<T>> for Type<&'a T> {
-                    //     fn from(o: &'a Type<T>) -> Type<&'a T> {
-                    //         o.as_ref()
-                    //     }
-                    // }
-
-                    // impl<'a, T> From<&'a mut Type<T>> for Type<&'a mut T> {
-                    //     fn from(o: &'a mut Type<T>) -> Type<&'a mut T> {
-                    //         o.as_mut()
-                    //     }
-                    // }
                 }
             ));
         }
-        option_impl
-    }
-}
-
-struct ResultLike;
+        if let Some(param) = some_param.filter(Rebindable::is_bare) {
+            let rebind = |with| args.rebind_generics(&[(param.param, with)]);
+            let bounds = |with| args.rebound_predicates(&[(param.param, with)]);
+            let pin_ref = quote!(::core::pin::Pin<&'__s #primary_inner>);
+            let pin_mut = quote!(::core::pin::Pin<&'__s mut #primary_inner>);
+            let pair = quote!((#primary_inner, _Other));
+            let nested = quote!(#typ #ty_generics);
+            let target_ref = quote!(&'__s <#primary_inner as ::core::ops::Deref>::Target);
+            let target_mut = quote!(&'__s mut <#primary_inner as ::core::ops::Deref>::Target);
+            let result = quote!(::core::result::Result<#primary_inner, _Error>);
+            option_impl = option_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_predicates: &where_predicates,
+                    where_clause: &where_clause,
+                    PrimaryValue: &primary_inner,
+                    OtherG: rebind(quote!(_Other)),
+                    OtherBounds: bounds(quote!(_Other)),
+                    PairG: rebind(pair.clone()),
+                    PairBounds: bounds(pair),
+                    ResultOfG: rebind(quote!(_Result)),
+                    ResultOfBounds: bounds(quote!(_Result)),
+                    PinRefG: rebind(pin_ref.clone()),
+                    PinRefBounds: bounds(pin_ref),
+                    PinMutG: rebind(pin_mut.clone()),
+                    PinMutBounds: bounds(pin_mut),
+                    NestedG: rebind(nested.clone()),
+                    NestedBounds: bounds(nested),
+                    ImplOtherG: args.impl_generics_with(impl_generics, quote!(_Other)),
+                    SomePat: some.pat("v"),
+                    SomeVal: some.value("v"),
+                    SomeNew: some.construct("v"),
+                    Secondary: secondary,
+                    TargetRefG: rebind(target_ref.clone()),
+                    TargetRefBounds: bounds(target_ref),
+                    TargetMutG: rebind(target_mut.clone()),
+                    TargetMutBounds: bounds(target_mut),
+                    ResultG: rebind(result.clone()),
+                    ResultBounds: bounds(result),
+                    ImplErrorG: args.impl_generics_with(impl_generics, quote!(_Error)),
+                },
+                {
+                    impl impl_generics Type ty_generics where_clause {
+                        #[inline]
+                        pub const fn as_pin_ref<'__s>(self: ::core::pin::Pin<&'__s Self>) -> Type PinRefG where PinRefBounds {
+                            match ::core::pin::Pin::get_ref(self) {
+                                SomePat => {
+                                    // SAFETY: the payload is pinned along with `self`.
+                                    let v = unsafe { ::core::pin::Pin::new_unchecked(v) };
+                                    SomeNew
+                                }
+                                Type::Secondary => Type::Secondary,
+                            }
+                        }
 
-impl LikeTrait for ResultLike {
-    fn data(&self) -> LikeData {
-        LikeData {
-            name: "ResultLike".to_owned(),
-            fields: (VariantFieldsType::Unnamed, VariantFieldsType::Unnamed),
-        }
-    }
+                        #[inline]
+                        pub const fn as_pin_mut<'__s>(self: ::core::pin::Pin<&'__s mut Self>) -> Type PinMutG where PinMutBounds {
+                            // SAFETY: the payload is pinned along with `self` and
+                            // never moved out of it.
+                            match unsafe { ::core::pin::Pin::get_unchecked_mut(self) } {
+                                SomePat => {
+                                    let v = unsafe { ::core::pin::Pin::new_unchecked(v) };
+                                    SomeNew
+                                }
+                                Type::Secondary => Type::Secondary,
+                            }
+                        }
 
-    fn quote_impl(&self, args: ImplArgs) -> Quote {
-        let ImplArgs {
-            typ,
-            primary,
-            secondary,
-            primary_inner,
-            secondary_inner,
-            ..
-        } = args;
-        let primary_inner = primary_inner.expect("primary_inner always exists for ResultLike");
-        let secondary_inner =
-            secondary_inner.expect("secondary_inner always exists for ResultLike");
-        let (impl_generics, ty_generics, where_clause, where_predicates) = args.split_for_impl();
-        let mut result_impl = Quote::new_call_site().quote_with(smart_quote!(
-            Vars {
-                Type: &typ,
-                impl_generics: &impl_generics,
-                ty_generics: &ty_generics,
-                where_predicates: &where_predicates,
-                where_clause: &where_clause,
-                Primary: primary,
-                Secondary: secondary,
-                T: primary_inner,
-                E: secondary_inner,
-            },
-            {
-                impl impl_generics result_like::ResultLike for Type ty_generics where_clause {
-                    type OkType = T;
-                    type ErrType = E;
-                }
-                impl impl_generics Type ty_generics where_clause {
-                    #[inline]
-                    pub fn from_result(result: Result<T, E>) -> Self {
-                        match result {
-                            Ok(v) => Type::Primary(v),
-                            Err(e) => Type::Secondary(e),
+                        #[inline]
+                        pub fn zip<_Other>(self, other: Type OtherG) -> Type PairG where OtherBounds PairBounds {
+                            Type::from_option(self.into_option().zip(other.into_option()))
                         }
-                    }
 
-                    #[inline]
-                    pub fn into_result(self) -> Result<T, E> {
-                        match self {
-                            Type::Primary(v) => Ok(v),
-                            Type::Secondary(e) => Err(e),
+                        #[inline]
+                        pub fn zip_with<_Other, _Function, _Result>(self, other: Type OtherG, f: _Function) -> Type ResultOfG
+                        where
+                            OtherBounds
+                            ResultOfBounds
+                            _Function: ::core::ops::FnOnce(PrimaryValue, _Other) -> _Result,
+                        {
+                            match (self.into_option(), other.into_option()) {
+                                (::core::option::Option::Some(v), ::core::option::Option::Some(other)) => {
+                                    Type::from_option(::core::option::Option::Some(f(v, other)))
+                                }
+                                _ => Type::from_option(::core::option::Option::None),
+                            }
                         }
                     }
 
-                    #[inline]
-                    pub fn as_result(&self) -> Result<&T, &E> {
-                        match self {
-                            Type::Primary(x) => Ok(x),
-                            Type::Secondary(x) => Err(x),
+                    impl ImplOtherG Type PairG where where_predicates OtherBounds PairBounds {
+                        #[inline]
+                        pub fn unzip(self) -> (Type ty_generics, Type OtherG) {
+                            let (v, other) = self.into_option().unzip();
+                            (Type::from_option(v), Type::from_option(other))
                         }
                     }
 
-                    #[inline]
-                    pub fn as_result_mut(&mut self) -> Result<&mut T, &mut E> {
-                        match self {
-                            Type::Primary(x) => Ok(x),
-                            Type::Secondary(x) => Err(x),
+                    impl impl_generics Type NestedG where where_predicates NestedBounds {
+                        #[inline]
+                        pub fn flatten(self) -> Type ty_generics {
+                            match self {
+                                SomePat => SomeVal,
+                                Type::Secondary => Type::Secondary,
+                            }
                         }
                     }
 
-
-                    #[inline]
-                    pub fn map_or<U, F: FnOnce(T) -> U>(self, default: U, f: F) -> U {
-                        match self {
-                            Type::Primary(t) => f(t),
-                            Type::Secondary(_) => default,
+                    impl impl_generics Type ty_generics where where_predicates PrimaryValue: ::core::ops::Deref {
+                        pub fn as_deref<'__s>(&'__s self) -> Type TargetRefG where TargetRefBounds {
+                            Type::from_option(self.as_option().map(|t| t.deref()))
                         }
                     }
 
-                    #[inline]
-                    pub fn unwrap_or(self, optb: T) -> T {
-                        self.into_result().unwrap_or(optb)
+                    impl impl_generics Type ty_generics where where_predicates PrimaryValue: ::core::ops::DerefMut {
+                        pub fn as_deref_mut<'__s>(&'__s mut self) -> Type TargetMutG where TargetMutBounds {
+                            Type::from_option(self.as_option_mut().map(|t| t.deref_mut()))
+                        }
                     }
 
-                    #[inline]
-                    pub fn unwrap_or_else<F: FnOnce(E) -> T>(self, op: F) -> T {
-                        self.into_result().unwrap_or_else(op)
+                    impl ImplErrorG Type ResultG where where_predicates ResultBounds {
+                        #[inline]
+                        pub fn transpose(self) -> ::core::result::Result<Type ty_generics, _Error> {
+                            self.into_option()
+                                .transpose()
+                                .map(|op| Type::from_option(op))
+                        }
                     }
                 }
+            ));
+        }
+        if some.is_single() && !some_param.is_some_and(|p| p.is_bare()) {
+            // The payload type can't be replaced, so dereferencing returns a
+            // std `Option` of the target instead.
+            option_impl = option_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_clause: &where_clause,
+                    PrimaryValue: &primary_inner,
+                },
+                {
+                    impl impl_generics Type ty_generics where_clause {
+                        #[inline]
+                        pub fn as_deref(&self) -> ::core::option::Option<&<PrimaryValue as ::core::ops::Deref>::Target>
+                        where
+                            for<'__r> PrimaryValue: ::core::ops::Deref,
+                        {
+                            self.as_option().map(::core::ops::Deref::deref)
+                        }
 
-                impl impl_generics From<Result<T, E>> for Type ty_generics where_clause {
-                    fn from(value: Result<T, E>) -> Self {
-                        Self::from_result(value)
+                        #[inline]
+                        pub fn as_deref_mut(&mut self) -> ::core::option::Option<&mut <PrimaryValue as ::core::ops::Deref>::Target>
+                        where
+                            for<'__r> PrimaryValue: ::core::ops::DerefMut,
+                        {
+                            self.as_option_mut().map(::core::ops::DerefMut::deref_mut)
+                        }
                     }
                 }
-
-                impl impl_generics From<Type ty_generics> for Result<T, E> where_clause {
-                    fn from(value: Type ty_generics) -> Self {
-                        value.into_result()
+            ));
+        }
+        if let Some(referent) = reference_payload(primary_fields) {
+            option_impl = option_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_clause: &where_clause,
+                    SomePat: some.pat("v"),
+                    Secondary: secondary,
+                    Referent: referent,
+                },
+                {
+                    impl impl_generics Type ty_generics where_clause {
+                        #[inline]
+                        pub const fn copied(self) -> ::core::option::Option<Referent> where for<'__r> Referent: ::core::marker::Copy {
+                            match self {
+                                SomePat => ::core::option::Option::Some(*v),
+                                Type::Secondary => ::core::option::Option::None,
+                            }
+                        }
+
+                        #[inline]
+                        pub fn cloned(self) -> ::core::option::Option<Referent> where for<'__r> Referent: ::core::clone::Clone {
+                            self.into_option().map(|t| t.clone())
+                        }
+                    }
+                }
+            ));
+        }
+        if args.aliases {
+            let is_primary = args.alias(primary, "is_{}")?;
+            let is_secondary = args.alias(secondary, "is_{}")?;
+            let unwrap_primary = args.alias(primary, "unwrap_{}")?;
+            let expect_primary = args.alias(primary, "expect_{}")?;
+            let map_primary = args.alias(primary, "map_{}")?;
+            let primary_or = args.alias(primary, "{}_or")?;
+            option_impl = option_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_clause: &where_clause,
+                    Primary: primary,
+                    Secondary: secondary,
+                    PrimaryValue: &primary_inner,
+                    SomePat: some.pat("v"),
+                    SomeVal: some.value("v"),
+                    ConstIfCopy: args.const_if_copy(&[primary_fields]),
+                    MapType: &map_type,
+                    MapBounds: &map_bounds,
+                    MapBody: if some_param.is_some_and(|p| p.is_bare()) {
+                        quote!(#typ::from_option(self.into_option().map(f)))
+                    } else {
+                        quote!(self.into_option().map(f))
+                    },
+                    IsPrimary: is_primary,
+                    IsSecondary: is_secondary,
+                    UnwrapPrimary: unwrap_primary,
+                    ExpectPrimary: expect_primary,
+                    MapPrimary: map_primary,
+                    PrimaryOr: primary_or,
+                },
+                {
+                    impl impl_generics Type ty_generics where_clause {
+                        #[inline]
+                        pub const fn IsPrimary(&self) -> bool {
+                            ::core::matches!(self, Type::Primary { .. })
+                        }
+
+                        #[inline]
+                        pub const fn IsSecondary(&self) -> bool {
+                            ::core::matches!(self, Type::Secondary)
+                        }
+
+                        #[inline]
+                        pub ConstIfCopy fn UnwrapPrimary(self) -> PrimaryValue {
+                            match self {
+                                SomePat => SomeVal,
+                                Type::Secondary => ::core::panic!("called `Option::unwrap()` on a `None` value"),
+                            }
+                        }
+
+                        #[inline]
+                        pub ConstIfCopy fn ExpectPrimary(self, msg: &str) -> PrimaryValue {
+                            match self {
+                                SomePat => SomeVal,
+                                Type::Secondary => ::core::panic!("{}", msg),
+                            }
+                        }
+
+                        #[inline]
+                        pub fn MapPrimary<_Other, _Function: ::core::ops::FnOnce(PrimaryValue) -> _Other>(self, f: _Function) -> MapType where MapBounds {
+                            MapBody
+                        }
+
+                        #[inline]
+                        pub ConstIfCopy fn PrimaryOr(self, default: PrimaryValue) -> PrimaryValue {
+                            match self {
+                                SomePat => SomeVal,
+                                Type::Secondary => default,
+                            }
+                        }
+                    }
+                }
+            ));
+        }
+        if args.try_trait {
+            option_impl = option_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_clause: &where_clause,
+                    SomePat: some.pat("v"),
+                    SomeVal: some.value("v"),
+                    SomeNew: some.construct("v"),
+                    Secondary: secondary,
+                    PrimaryValue: &primary_inner,
+                },
+                {
+                    impl impl_generics ::core::ops::Try for Type ty_generics where_clause {
+                        type Output = PrimaryValue;
+                        type Residual = ::core::option::Option<::core::convert::Infallible>;
+
+                        #[inline]
+                        fn from_output(v: PrimaryValue) -> Self {
+                            SomeNew
+                        }
+
+                        #[inline]
+                        fn branch(self) -> ::core::ops::ControlFlow<Self::Residual, PrimaryValue> {
+                            match self {
+                                SomePat => ::core::ops::ControlFlow::Continue(SomeVal),
+                                Type::Secondary => ::core::ops::ControlFlow::Break(::core::option::Option::None),
+                            }
+                        }
+                    }
+
+                    impl impl_generics ::core::ops::FromResidual<::core::option::Option<::core::convert::Infallible>> for Type ty_generics where_clause {
+                        #[inline]
+                        fn from_residual(_: ::core::option::Option<::core::convert::Infallible>) -> Self {
+                            Type::Secondary
+                        }
+                    }
+                }
+            ));
+        }
+        Ok(option_impl)
+    }
+}
+
+struct ResultLike;
+
+impl LikeTrait for ResultLike {
+    fn data(&self) -> LikeData {
+        LikeData {
+            name: "ResultLike".to_owned(),
+            attr: "result_like",
+            roles: ("ok", "err"),
+            core: &["from_result", "into_result", "as_result", "as_result_mut"],
+            fields: (VariantFieldsType::Payload, VariantFieldsType::Payload),
+        }
+    }
+
+    fn quote_impl(&self, args: ImplArgs) -> syn::Result<Quote> {
+        let ImplArgs {
+            typ,
+            primary,
+            secondary,
+            primary_fields,
+            secondary_fields,
+            ..
+        } = args;
+        let ok = args.primary_payload();
+        let err = args.secondary_payload();
+        let primary_inner = ok.ty();
+        let secondary_inner = err.ty();
+        let (impl_generics, ty_generics, where_clause, where_predicates) = args.split_for_impl();
+        let ok_param = args.rebindable_param(primary_fields, &[secondary_fields]);
+        let err_param = args.rebindable_param(secondary_fields, &[primary_fields]);
+        // `Type<..>` with either payload replaced by `_Other` and the bounds
+        // it needs; see `OptionLike`.
+        let map_type = |param: Option<Rebindable>, std| match param.filter(Rebindable::is_bare) {
+            Some(param) => {
+                let generics = args.rebind_generics(&[(param.param, quote!(_Other))]);
+                (
+                    quote!(#typ #generics),
+                    args.rebound_predicates(&[(param.param, quote!(_Other))]),
+                )
+            }
+            None => (std, quote!()),
+        };
+        let (map_ok_type, map_ok_bounds) = map_type(
+            ok_param,
+            quote!(::core::result::Result<_Other, #secondary_inner>),
+        );
+        let (map_err_type, map_err_bounds) = map_type(
+            err_param,
+            quote!(::core::result::Result<#primary_inner, _Other>),
+        );
+        let rebind_ok = if map_ok_bounds.is_empty() {
+            map_ok_type.clone()
+        } else {
+            quote!(::core::result::Result<_Other, #secondary_inner>)
+        };
+        let rebind_err = if map_err_bounds.is_empty() {
+            map_err_type.clone()
+        } else {
+            quote!(::core::result::Result<#primary_inner, _Other>)
+        };
+        let mut result_impl = Quote::new_call_site().quote_with(smart_quote!(
+            Vars {
+                OkPat: ok.pat("v"),
+                OkVal: ok.value("v"),
+                OkNew: ok.construct("v"),
+                ErrPat: err.pat("e"),
+                ErrVal: err.value("e"),
+                ErrNew: err.construct("e"),
+                OkRef: ok.ref_ty(None),
+                OkMut: ok.ref_ty(Some(Default::default())),
+                ErrRef: err.ref_ty(None),
+                ErrMut: err.ref_ty(Some(Default::default())),
+                Type: &typ,
+                impl_generics: &impl_generics,
+                ty_generics: &ty_generics,
+                where_predicates: &where_predicates,
+                where_clause: &where_clause,
+                Primary: primary,
+                Secondary: secondary,
+                T: &primary_inner,
+                E: &secondary_inner,
+                ConstIfCopy: args.const_if_copy(&[primary_fields, secondary_fields]),
+            },
+            {
+                impl impl_generics Type ty_generics where_clause {
+                    #[inline]
+                    pub ConstIfCopy fn from_result(result: ::core::result::Result<T, E>) -> Self {
+                        match result {
+                            ::core::result::Result::Ok(v) => OkNew,
+                            ::core::result::Result::Err(e) => ErrNew,
+                        }
+                    }
+
+                    #[inline]
+                    pub ConstIfCopy fn into_result(self) -> ::core::result::Result<T, E> {
+                        match self {
+                            OkPat => ::core::result::Result::Ok(OkVal),
+                            ErrPat => ::core::result::Result::Err(ErrVal),
+                        }
+                    }
+
+                    #[inline]
+                    pub const fn as_result(&self) -> ::core::result::Result<OkRef, ErrRef> {
+                        match self {
+                            OkPat => ::core::result::Result::Ok(OkVal),
+                            ErrPat => ::core::result::Result::Err(ErrVal),
+                        }
+                    }
+
+                    #[inline]
+                    pub const fn as_result_mut(&mut self) -> ::core::result::Result<OkMut, ErrMut> {
+                        match self {
+                            OkPat => ::core::result::Result::Ok(OkVal),
+                            ErrPat => ::core::result::Result::Err(ErrVal),
+                        }
+                    }
+
+                    #[inline]
+                    pub const fn is_ok(&self) -> bool {
+                        ::core::matches!(self, Type::Primary { .. })
+                    }
+
+                    #[inline]
+                    pub const fn is_err(&self) -> bool {
+                        ::core::matches!(self, Type::Secondary { .. })
+                    }
+
+                    #[inline]
+                    pub fn is_ok_and<F: ::core::ops::FnOnce(T) -> bool>(self, f: F) -> bool {
+                        self.into_result().is_ok_and(f)
+                    }
+
+                    #[inline]
+                    pub fn is_err_and<F: ::core::ops::FnOnce(E) -> bool>(self, f: F) -> bool {
+                        self.into_result().is_err_and(f)
+                    }
+
+                    #[inline]
+                    pub ConstIfCopy fn ok(self) -> ::core::option::Option<T> {
+                        match self {
+                            OkPat => ::core::option::Option::Some(OkVal),
+                            Type::Secondary { .. } => ::core::option::Option::None,
+                        }
+                    }
+
+                    #[inline]
+                    pub ConstIfCopy fn err(self) -> ::core::option::Option<E> {
+                        match self {
+                            Type::Primary { .. } => ::core::option::Option::None,
+                            ErrPat => ::core::option::Option::Some(ErrVal),
+                        }
+                    }
+
+                    #[inline]
+                    pub fn map_or<U, F: ::core::ops::FnOnce(T) -> U>(self, default: U, f: F) -> U {
+                        match self {
+                            OkPat => f(OkVal),
+                            Type::Secondary { .. } => default,
+                        }
+                    }
+
+                    #[inline]
+                    pub fn map_or_else<U, D: ::core::ops::FnOnce(E) -> U, F: ::core::ops::FnOnce(T) -> U>(
+                        self,
+                        default: D,
+                        f: F,
+                    ) -> U {
+                        self.into_result().map_or_else(default, f)
+                    }
+
+                    #[inline]
+                    pub fn update<F: ::core::ops::FnOnce(T) -> T>(self, op: F) -> Self {
+                        match self {
+                            OkPat => {
+                                let v = op(OkVal);
+                                OkNew
+                            }
+                            err => err,
+                        }
+                    }
+
+                    #[inline]
+                    pub fn update_err<O: ::core::ops::FnOnce(E) -> E>(self, op: O) -> Self {
+                        match self {
+                            ErrPat => {
+                                let e = op(ErrVal);
+                                ErrNew
+                            }
+                            ok => ok,
+                        }
+                    }
+
+                    #[inline]
+                    pub fn inspect<F: ::core::ops::FnOnce(&T)>(self, f: F) -> Self {
+                        match self {
+                            OkPat => {
+                                let v = OkVal;
+                                f(&v);
+                                OkNew
+                            }
+                            err => err,
+                        }
+                    }
+
+                    #[inline]
+                    pub fn inspect_err<F: ::core::ops::FnOnce(&E)>(self, f: F) -> Self {
+                        match self {
+                            ErrPat => {
+                                let e = ErrVal;
+                                f(&e);
+                                ErrNew
+                            }
+                            ok => ok,
+                        }
+                    }
+
+                    #[inline]
+                    pub fn iter(&self) -> ::core::option::IntoIter<OkRef> {
+                        self.as_result().ok().into_iter()
+                    }
+
+                    #[inline]
+                    pub fn iter_mut(&mut self) -> ::core::option::IntoIter<OkMut> {
+                        self.as_result_mut().ok().into_iter()
+                    }
+
+                    #[inline]
+                    pub ConstIfCopy fn unwrap_or(self, optb: T) -> T {
+                        match self {
+                            OkPat => OkVal,
+                            Type::Secondary { .. } => optb,
+                        }
+                    }
+
+                    #[inline]
+                    pub fn unwrap_or_else<F: ::core::ops::FnOnce(E) -> T>(self, op: F) -> T {
+                        self.into_result().unwrap_or_else(op)
+                    }
+
+                    #[inline]
+                    pub fn unwrap_or_default(self) -> T where for<'__r> T: ::core::default::Default {
+                        self.into_result().unwrap_or_default()
+                    }
+
+                    /// # Safety
+                    ///
+                    /// Calling this method on the err variant is undefined behavior.
+                    #[inline]
+                    pub ConstIfCopy unsafe fn unwrap_unchecked(self) -> T {
+                        match self {
+                            OkPat => OkVal,
+                            Type::Secondary { .. } => unsafe { ::core::hint::unreachable_unchecked() },
+                        }
+                    }
+
+                    /// # Safety
+                    ///
+                    /// Calling this method on the ok variant is undefined behavior.
+                    #[inline]
+                    pub ConstIfCopy unsafe fn unwrap_err_unchecked(self) -> E {
+                        match self {
+                            Type::Primary { .. } => unsafe { ::core::hint::unreachable_unchecked() },
+                            ErrPat => ErrVal,
+                        }
+                    }
+
+                    // Older compilers see through the trivially false bound on
+                    // non-generic enums and flag the body as unreachable.
+                    #[inline]
+                    #[allow(unreachable_code)]
+                    pub fn into_ok(self) -> T where for<'__r> E: ::core::convert::Into<::core::convert::Infallible> {
+                        match self {
+                            OkPat => OkVal,
+                            ErrPat => match ::core::convert::Into::<::core::convert::Infallible>::into(ErrVal) {},
+                        }
+                    }
+
+                    #[inline]
+                    #[allow(unreachable_code)]
+                    pub fn into_err(self) -> E where for<'__r> T: ::core::convert::Into<::core::convert::Infallible> {
+                        match self {
+                            OkPat => match ::core::convert::Into::<::core::convert::Infallible>::into(OkVal) {},
+                            ErrPat => ErrVal,
+                        }
+                    }
+                }
+
+                impl impl_generics ::core::convert::From<::core::result::Result<T, E>> for Type ty_generics where_clause {
+                    fn from(value: ::core::result::Result<T, E>) -> Self {
+                        Self::from_result(value)
+                    }
+                }
+
+                impl impl_generics ::core::convert::From<Type ty_generics> for ::core::result::Result<T, E> where_clause {
+                    fn from(value: Type ty_generics) -> Self {
+                        value.into_result()
+                    }
+                }
+
+                impl impl_generics Type ty_generics where where_predicates E: ::core::fmt::Debug {
+                    #[inline]
+                    pub fn expect(self, msg: &str) -> T {
+                        self.into_result().expect(msg)
+                    }
+
+                    #[inline]
+                    pub fn unwrap(self) -> T {
+                        self.into_result().unwrap()
+                    }
+                }
+
+                impl impl_generics Type ty_generics where where_predicates T: ::core::fmt::Debug {
+                    #[inline]
+                    pub fn expect_err(self, msg: &str) -> E {
+                        self.into_result().expect_err(msg)
+                    }
+
+                    #[inline]
+                    pub fn unwrap_err(self) -> E {
+                        self.into_result().unwrap_err()
+                    }
+                }
+
+                impl impl_generics ::core::iter::IntoIterator for Type ty_generics where_clause {
+                    type Item = T;
+                    type IntoIter = ::core::result::IntoIter<T>;
+
+                    #[inline]
+                    fn into_iter(self) -> ::core::result::IntoIter<T> {
+                        self.into_result().into_iter()
+                    }
+                }
+            }
+        ));
+        if ok.is_single() && err.is_single() {
+            result_impl = result_impl.quote_with(smart_quote!(
+                Vars {
+                    result_like: args.krate,
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_clause: &where_clause,
+                    T: &primary_inner,
+                    E: &secondary_inner,
+                    RebindOkType: &rebind_ok,
+                    RebindErrType: &rebind_err,
+                },
+                {
+                    impl impl_generics result_like::ResultLike for Type ty_generics where_clause {
+                        type OkType = T;
+                        type ErrType = E;
+                        type RebindOk<_Other> = RebindOkType;
+                        type RebindErr<_Other> = RebindErrType;
+
+                        #[inline]
+                        fn from_result(result: ::core::result::Result<T, E>) -> Self {
+                            Type::from_result(result)
+                        }
+
+                        #[inline]
+                        fn into_result(self) -> ::core::result::Result<T, E> {
+                            Type::into_result(self)
+                        }
+
+                        #[inline]
+                        fn as_result(&self) -> ::core::result::Result<&T, &E> {
+                            Type::as_result(self)
+                        }
+
+                        #[inline]
+                        fn as_result_mut(&mut self) -> ::core::result::Result<&mut T, &mut E> {
+                            Type::as_result_mut(self)
+                        }
+                    }
+                }
+            ));
+        }
+        // Without a type parameter to replace, payload-changing combinators
+        // return std types; see `OptionLike`.
+        if ok_param.is_none() {
+            result_impl = result_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_clause: &where_clause,
+                    T: &primary_inner,
+                    E: &secondary_inner,
+                },
+                {
+                    impl impl_generics Type ty_generics where_clause {
+                        #[inline]
+                        pub fn map<U, F: ::core::ops::FnOnce(T) -> U>(self, op: F) -> ::core::result::Result<U, E> {
+                            self.into_result().map(op)
+                        }
+
+                        #[inline]
+                        pub fn and<U>(self, res: ::core::result::Result<U, E>) -> ::core::result::Result<U, E> {
+                            self.into_result().and(res)
+                        }
+
+                        #[inline]
+                        pub fn and_then<U, F: ::core::ops::FnOnce(T) -> ::core::result::Result<U, E>>(self, op: F) -> ::core::result::Result<U, E> {
+                            self.into_result().and_then(op)
+                        }
+                    }
+                }
+            ));
+        }
+        if err_param.is_none() {
+            result_impl = result_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_clause: &where_clause,
+                    T: &primary_inner,
+                    E: &secondary_inner,
+                },
+                {
+                    impl impl_generics Type ty_generics where_clause {
+                        #[inline]
+                        pub fn map_err<F, O: ::core::ops::FnOnce(E) -> F>(self, op: O) -> ::core::result::Result<T, F> {
+                            self.into_result().map_err(op)
+                        }
+
+                        #[inline]
+                        pub fn or<F>(self, res: ::core::result::Result<T, F>) -> ::core::result::Result<T, F> {
+                            self.into_result().or(res)
+                        }
+
+                        #[inline]
+                        pub fn or_else<F, O: ::core::ops::FnOnce(E) -> ::core::result::Result<T, F>>(self, op: O) -> ::core::result::Result<T, F> {
+                            self.into_result().or_else(op)
+                        }
                     }
                 }
+            ));
+        }
+        if ok_param.is_none() || err_param.is_none() {
+            result_impl = result_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_clause: &where_clause,
+                    OkRef: ok.ref_ty(None),
+                    OkMut: ok.ref_ty(Some(Default::default())),
+                    ErrRef: err.ref_ty(None),
+                    ErrMut: err.ref_ty(Some(Default::default())),
+                },
+                {
+                    impl impl_generics Type ty_generics where_clause {
+                        #[inline]
+                        pub const fn as_ref(&self) -> ::core::result::Result<OkRef, ErrRef> {
+                            self.as_result()
+                        }
 
-                impl impl_generics Type ty_generics where where_predicates E: core::fmt::Debug {
-                    #[inline]
-                    pub fn expect(self, msg: &str) -> T {
-                        self.into_result().expect(msg)
+                        #[inline]
+                        pub const fn as_mut(&mut self) -> ::core::result::Result<OkMut, ErrMut> {
+                            self.as_result_mut()
+                        }
                     }
+                }
+            ));
+        }
+        if ok_param.is_none()
+            && ok.is_single()
+            && err.is_single()
+            && reference_payload(primary_fields).is_none()
+        {
+            result_impl = result_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_clause: &where_clause,
+                    T: &primary_inner,
+                    E: &secondary_inner,
+                    OkPat: ok.pat("v"),
+                    ErrPat: err.pat("e"),
+                },
+                {
+                    impl impl_generics Type ty_generics where_clause {
+                        #[inline]
+                        pub const fn copied(&self) -> ::core::result::Result<T, E>
+                        where
+                            for<'__r> T: ::core::marker::Copy,
+                            for<'__r> E: ::core::marker::Copy,
+                        {
+                            match self {
+                                OkPat => ::core::result::Result::Ok(*v),
+                                ErrPat => ::core::result::Result::Err(*e),
+                            }
+                        }
 
-                    #[inline]
-                    pub fn unwrap(self) -> T {
-                        self.into_result().unwrap()
+                        #[inline]
+                        pub fn cloned(&self) -> ::core::result::Result<T, E>
+                        where
+                            for<'__r> T: ::core::clone::Clone,
+                            for<'__r> E: ::core::clone::Clone,
+                        {
+                            match self {
+                                OkPat => ::core::result::Result::Ok(<T as ::core::clone::Clone>::clone(v)),
+                                ErrPat => ::core::result::Result::Err(<E as ::core::clone::Clone>::clone(e)),
+                            }
+                        }
                     }
                 }
+            ));
+        }
 
-                impl impl_generics Type ty_generics where where_predicates T: core::fmt::Debug {
-                    // #[inline]
-                    // pub fn expect_err(self, msg: &str) -> E {
-                    //     self.into_result().expect_err(msg)
-                    // }
+        if ok.is_single() {
+            result_impl = result_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    ty_generics: &ty_generics,
+                    where_clause: &where_clause,
+                    T: &primary_inner,
+                    ImplRefG: args.impl_generics_with(&impl_generics, quote!('__a)),
+                },
+                {
+                    impl ImplRefG ::core::iter::IntoIterator for &'__a Type ty_generics where_clause {
+                        type Item = &'__a T;
+                        type IntoIter = ::core::option::IntoIter<&'__a T>;
 
-                    #[inline]
-                    pub fn unwrap_err(self) -> E {
-                        self.into_result().unwrap_err()
+                        #[inline]
+                        fn into_iter(self) -> ::core::option::IntoIter<&'__a T> {
+                            self.as_result().ok().into_iter()
+                        }
                     }
-                }
 
-                // into_ok
+                    impl ImplRefG ::core::iter::IntoIterator for &'__a mut Type ty_generics where_clause {
+                        type Item = &'__a mut T;
+                        type IntoIter = ::core::option::IntoIter<&'__a mut T>;
 
-                impl impl_generics IntoIterator for Type ty_generics {
-                    type Item = T;
-                    type IntoIter = core::result::IntoIter<T>;
-
-                    #[inline]
-                    fn into_iter(self) -> core::result::IntoIter<T> {
-                        self.into_result().into_iter()
+                        #[inline]
+                        fn into_iter(self) -> ::core::option::IntoIter<&'__a mut T> {
+                            self.as_result_mut().ok().into_iter()
+                        }
                     }
                 }
-            }
-        ));
-        let param_symbols: Vec<_> = ty_generics
-            .params
-            .iter()
-            .filter_map(|p| match p {
-                syn::GenericParam::Type(type_param) => Some(type_param.ident.to_string()),
-                _ => None,
-            })
-            .collect();
-        let primary_is_generic = primary_inner
-            .iter()
-            .next()
-            .is_some_and(|f| param_symbols.contains(&f.ty.to_token_stream().to_string()));
-        let secondary_is_generic = secondary_inner
-            .iter()
-            .next()
-            .is_some_and(|f| param_symbols.contains(&f.ty.to_token_stream().to_string()));
-        let everything_is_generic = primary_is_generic && secondary_is_generic;
-
-        // println!(
-        //     "flags {:?}  {} {} {}",
-        //     param_symbols, primary_is_generic, secondary_is_generic, everything_is_generic
-        // );
+            ));
+        }
 
-        if primary_is_generic {
+        if let Some(ok_param) = ok_param {
+            let ok_rebind = |with| args.rebind_generics(&[(ok_param.param, with)]);
+            let ok_bounds = |with| args.rebound_predicates(&[(ok_param.param, with)]);
+            let ok_elem = ok_param.param;
+            // The error is moved along too, so it has to be `Copy` as well.
+            let copied_const = args
+                .const_if_copy(&[secondary_fields])
+                .filter(|_| ok_param.is_bare());
+            let copied_body = |each: proc_macro2::TokenStream| {
+                if ok_param.is_bare() {
+                    let (ok_pat, ok_new) = (ok.pat("v"), ok.construct("v"));
+                    let err_pat = err.pat("e");
+                    quote!(match self {
+                        #ok_pat => {
+                            let v = *v;
+                            #ok_new
+                        }
+                        #err_pat => #err_pat,
+                    })
+                } else {
+                    let each = ok_param.each(each);
+                    quote!(#typ::from_result(self.into_result().map(#each)))
+                }
+            };
             result_impl = result_impl.quote_with(smart_quote!(
                 Vars {
+                    OkPat: ok.pat("v"),
+                    OkVal: ok.value("v"),
+                    OkNew: ok.construct("v"),
+                    ErrPat: err.pat("e"),
+                    ErrRewrap: err.pat("e"),
                     Type: &typ,
                     impl_generics: &impl_generics,
                     ty_generics: &ty_generics,
                     where_predicates: &where_predicates,
                     where_clause: &where_clause,
                     Primary: primary,
-                    Secondary: secondary,
-                    T: primary_inner,
-                    E: secondary_inner,
-                    GenericE: if secondary_is_generic { Some(secondary_inner) } else { None },
+                    T: &primary_inner,
+                    Elem: ok_elem,
+                    UPayload: ok_param.payload(quote!(U)),
+                    OkU: ok_rebind(quote!(U)),
+                    UBounds: ok_bounds(quote!(U)),
+                    ImplRefG: args.impl_generics_with(impl_generics, quote!('__a)),
+                    OkRef: ok_rebind(quote!(&'__a #ok_elem)),
+                    OkMut: ok_rebind(quote!(&'__a mut #ok_elem)),
+                    OkRefBounds: ok_bounds(quote!(&'__a #ok_elem)),
+                    OkMutBounds: ok_bounds(quote!(&'__a mut #ok_elem)),
+                    ConstIfCopy: copied_const,
+                    CopyRefBody: copied_body(quote!(|&t| t)),
+                    CopyMutBody: copied_body(quote!(|&mut t| t)),
+                    CloneEach: ok_param.each(quote!(|t| <#ok_elem as ::core::clone::Clone>::clone(t))),
                 },
                 {
                     impl impl_generics Type ty_generics where_clause {
                         #[inline]
-                        pub fn map<U, F: FnOnce(T) -> U>(self, op: F) -> Type<U, GenericE> {
+                        pub fn map<U, F: ::core::ops::FnOnce(T) -> UPayload>(self, op: F) -> Type OkU where UBounds {
                             match self {
-                                Type::Primary(t) => Type::Primary(op(t)),
-                                Type::Secondary(e) => Type::Secondary(e),
+                                OkPat => {
+                                    let v = op(OkVal);
+                                    OkNew
+                                }
+                                ErrPat => ErrRewrap,
                             }
                         }
                         #[inline]
-                        pub fn map_or_else<U, M: FnOnce(T) -> U, F: FnOnce(E) -> U>(
-                            self,
-                            fallback: F,
-                            map: M,
-                        ) -> U {
-                            self.map(map).unwrap_or_else(fallback)
-                        }
-                        #[inline]
-                        pub fn and<U>(self, res: Type<U, GenericE>) -> Type<U, GenericE> {
+                        pub fn and<U>(self, res: Type OkU) -> Type OkU where UBounds {
                             match self {
-                                Type::Primary(_) => res,
-                                Type::Secondary(e) => Type::Secondary(e),
+                                Type::Primary { .. } => res,
+                                ErrPat => ErrRewrap,
                             }
                         }
                         #[inline]
-                        pub fn and_then<U, F: FnOnce(T) -> Type<U, GenericE>>(self, op: F) -> Type<U, GenericE> {
+                        pub fn and_then<U, F: ::core::ops::FnOnce(T) -> Type OkU>(self, op: F) -> Type OkU where UBounds {
                             match self {
-                                Type::Primary(t) => op(t),
-                                Type::Secondary(e) => Type::Secondary(e),
+                                OkPat => op(OkVal),
+                                ErrPat => ErrRewrap,
                             }
                         }
                     }
 
-                    impl impl_generics Type ty_generics where where_predicates T: Default {
-                        #[inline]
-                        pub fn unwrap_or_default(self) -> T {
-                            self.into_result().unwrap_or_default()
-                        }
-                    }
-
-                    impl impl_generics Type<&T, GenericE> where where_predicates T: Copy {
-                        pub fn copied(self) -> Type ty_generics {
-                            self.map(|&t| t)
+                    impl ImplRefG Type OkRef where where_predicates OkRefBounds Elem: ::core::marker::Copy {
+                        pub ConstIfCopy fn copied(self) -> Type ty_generics {
+                            CopyRefBody
                         }
                     }
-                    impl impl_generics Type<&mut T, GenericE> where where_predicates T: Copy {
-                        pub fn copied(self) -> Type ty_generics {
-                            self.map(|&mut t| t)
+                    impl ImplRefG Type OkMut where where_predicates OkMutBounds Elem: ::core::marker::Copy {
+                        pub ConstIfCopy fn copied(self) -> Type ty_generics {
+                            CopyMutBody
                         }
                     }
 
-                    impl impl_generics Type<&T, GenericE> where where_predicates T: Clone {
+                    impl ImplRefG Type OkRef where where_predicates OkRefBounds Elem: ::core::clone::Clone {
                         pub fn cloned(self) -> Type ty_generics {
-                            self.map(|t| t.clone())
+                            Type::from_result(self.into_result().map(CloneEach))
                         }
                     }
-                    impl impl_generics Type<&mut T, GenericE> where where_predicates T: Clone {
+                    impl ImplRefG Type OkMut where where_predicates OkMutBounds Elem: ::core::clone::Clone {
                         pub fn cloned(self) -> Type ty_generics {
-                            self.map(|t| t.clone())
+                            Type::from_result(self.into_result().map(CloneEach))
                         }
                     }
+                }
+            ));
+            if ok_param.is_bare() {
+                let option = quote!(::core::option::Option<#primary_inner>);
+                let nested = quote!(#typ #ty_generics);
+                result_impl = result_impl.quote_with(smart_quote!(
+                    Vars {
+                        Type: &typ,
+                        impl_generics: &impl_generics,
+                        ty_generics: &ty_generics,
+                        where_predicates: &where_predicates,
+                        OkOption: ok_rebind(option.clone()),
+                        OptionBounds: ok_bounds(option),
+                        OkNested: ok_rebind(nested.clone()),
+                        NestedBounds: ok_bounds(nested),
+                        OkPat: ok.pat("v"),
+                        OkVal: ok.value("v"),
+                        ErrPat: err.pat("e"),
+                        ErrRewrap: err.pat("e"),
+                    },
+                    {
+                        impl impl_generics Type OkOption where where_predicates OptionBounds {
+                            #[inline]
+                            pub fn transpose(self) -> ::core::option::Option<Type ty_generics> {
+                                self.into_result()
+                                    .transpose()
+                                    .map(|r| Type::from_result(r))
+                            }
+                        }
 
-                    impl impl_generics Type<Option<T>, GenericE> {
-                        #[inline]
-                        pub fn transpose(self) -> Option<Type<T, GenericE>> {
-                            self.into_result()
-                                .transpose()
-                                .map(|r| Type::from_result(r))
+                        impl impl_generics Type OkNested where where_predicates NestedBounds {
+                            #[inline]
+                            pub fn flatten(self) -> Type ty_generics {
+                                match self {
+                                    OkPat => OkVal,
+                                    ErrPat => ErrRewrap,
+                                }
+                            }
                         }
                     }
-                }
-            ));
+                ));
+            }
         }
 
-        if secondary_is_generic {
+        if let Some(err_param) = err_param {
+            let err_rebind = |with| args.rebind_generics(&[(err_param.param, with)]);
+            let err_bounds = args.rebound_predicates(&[(err_param.param, quote!(F))]);
             result_impl = result_impl.quote_with(smart_quote!(
                 Vars {
+                    OkPat: ok.pat("v"),
+                    OkRewrap: ok.pat("v"),
+                    ErrPat: err.pat("e"),
+                    ErrVal: err.value("e"),
+                    ErrNew: err.construct("e"),
                     Type: &typ,
                     impl_generics: &impl_generics,
                     ty_generics: &ty_generics,
-                    // where_predicates: &where_predicates,
                     where_clause: &where_clause,
-                    Primary: primary,
                     Secondary: secondary,
-                    E: secondary_inner,
-                    GenericT: if primary_is_generic { Some(quote!(#primary_inner ,)) } else { None },
+                    E: &secondary_inner,
+                    FPayload: err_param.payload(quote!(F)),
+                    ErrF: err_rebind(quote!(F)),
+                    FBounds: &err_bounds,
                 },
                 {
                     impl impl_generics Type ty_generics where_clause {
                         #[inline]
-                        pub fn map_err<F, O: FnOnce(E) -> F>(self, op: O) -> Type<GenericT F> {
+                        pub fn map_err<F, O: ::core::ops::FnOnce(E) -> FPayload>(self, op: O) -> Type ErrF where FBounds {
                             match self {
-                                Type::Primary(t) => Type::Primary(t),
-                                Type::Secondary(e) => Type::Secondary(op(e)),
+                                OkPat => OkRewrap,
+                                ErrPat => {
+                                    let e = op(ErrVal);
+                                    ErrNew
+                                }
                             }
                         }
 
                         #[inline]
-                        pub fn or<F>(self, res: Type<GenericT F>) -> Type<GenericT F> {
+                        pub fn or<F>(self, res: Type ErrF) -> Type ErrF where FBounds {
                             match self {
-                                Type::Primary(v) => Type::Primary(v),
-                                Type::Secondary(_) => res,
+                                OkPat => OkRewrap,
+                                Type::Secondary { .. } => res,
                             }
                         }
                         #[inline]
-                        pub fn or_else<F, O: FnOnce(E) -> Type<GenericT F>>(self, op: O) -> Type<GenericT F> {
+                        pub fn or_else<F, O: ::core::ops::FnOnce(E) -> Type ErrF>(self, op: O) -> Type ErrF where FBounds {
                             match self {
-                                Type::Primary(t) => Type::Primary(t),
-                                Type::Secondary(e) => op(e),
+                                OkPat => OkRewrap,
+                                ErrPat => op(ErrVal),
                             }
                         }
                     }
@@ -908,125 +2715,493 @@ impl LikeTrait for ResultLike {
             ));
         }
 
-        if everything_is_generic {
+        if let (Some(ok_param), Some(err_param)) = (ok_param, err_param) {
+            let both_rebind =
+                |ok, err| args.rebind_generics(&[(ok_param.param, ok), (err_param.param, err)]);
+            let both_bounds =
+                |ok, err| args.rebound_predicates(&[(ok_param.param, ok), (err_param.param, err)]);
+            let (ok_elem, err_elem) = (ok_param.param, err_param.param);
+            let ok_new = ok.construct("v");
+            let err_new = err.construct("e");
+            let as_ref_arms = (
+                if ok_param.is_bare() {
+                    ok_new.clone()
+                } else {
+                    quote!({ let v = v.each_ref(); #ok_new })
+                },
+                if err_param.is_bare() {
+                    err_new.clone()
+                } else {
+                    quote!({ let e = e.each_ref(); #err_new })
+                },
+            );
+            let as_mut_arms = (
+                if ok_param.is_bare() {
+                    ok_new
+                } else {
+                    quote!({ let v = v.each_mut(); #ok_new })
+                },
+                if err_param.is_bare() {
+                    err_new
+                } else {
+                    quote!({ let e = e.each_mut(); #err_new })
+                },
+            );
             result_impl = result_impl.quote_with(smart_quote!(
                 Vars {
+                    OkPat: ok.pat("v"),
+                    OkVal: ok.value("v"),
+                    OkNew: ok.construct("v"),
+                    ErrPat: err.pat("e"),
+                    ErrVal: err.value("e"),
+                    ErrNew: err.construct("e"),
+                    OkFromPat: ok.pat("from"),
+                    OkFromVal: ok.value("from"),
+                    ErrFromPat: err.pat("from"),
+                    ErrFromVal: err.value("from"),
                     Type: &typ,
                     impl_generics: &impl_generics,
                     ty_generics: &ty_generics,
                     where_predicates: &where_predicates,
                     where_clause: &where_clause,
-                    Primary: primary,
-                    Secondary: secondary,
-                    T: primary_inner,
-                    E: secondary_inner,
+                    T: &primary_inner,
+                    E: &secondary_inner,
+                    BothRef: both_rebind(quote!(&'__s #ok_elem), quote!(&'__s #err_elem)),
+                    BothMut: both_rebind(quote!(&'__s mut #ok_elem), quote!(&'__s mut #err_elem)),
+                    RefBounds: both_bounds(quote!(&'__s #ok_elem), quote!(&'__s #err_elem)),
+                    MutBounds: both_bounds(quote!(&'__s mut #ok_elem), quote!(&'__s mut #err_elem)),
+                    ConstIfBare: (ok_param.is_bare() && err_param.is_bare()).then(<syn::Token![const]>::default),
+                    OkRefArm: as_ref_arms.0,
+                    ErrRefArm: as_ref_arms.1,
+                    OkMutArm: as_mut_arms.0,
+                    ErrMutArm: as_mut_arms.1,
                 },
                 {
                 impl impl_generics Type ty_generics where_clause {
-                    // contains
-                    // contains_err
-
                     #[inline]
-                    pub fn as_ref(&self) -> Type<&T, &E> {
+                    pub ConstIfBare fn as_ref<'__s>(&'__s self) -> Type BothRef where RefBounds {
                         match self {
-                            Type::Primary(x) => Type::Primary(x),
-                            Type::Secondary(x) => Type::Secondary(x),
+                            OkPat => OkRefArm,
+                            ErrPat => ErrRefArm,
                         }
                     }
 
                     #[inline]
-                    pub fn as_mut(&mut self) -> Type<&mut T, &mut E> {
+                    pub ConstIfBare fn as_mut<'__s>(&'__s mut self) -> Type BothMut where MutBounds {
                         match self {
-                            Type::Primary(x) => Type::Primary(x),
-                            Type::Secondary(x) => Type::Secondary(x),
+                            OkPat => OkMutArm,
+                            ErrPat => ErrMutArm,
                         }
                     }
-
-                    // iter
-                    // iter_mut
                 }
 
-                impl impl_generics Copy for Type ty_generics where where_predicates T: Copy, E: Copy { }
-                impl impl_generics Clone for Type ty_generics where where_predicates T: Clone, E: Clone {
+                impl impl_generics ::core::marker::Copy for Type ty_generics where where_predicates T: ::core::marker::Copy, E: ::core::marker::Copy { }
+                impl impl_generics ::core::clone::Clone for Type ty_generics where where_predicates T: ::core::clone::Clone, E: ::core::clone::Clone {
                     #[inline]
                     fn clone(&self) -> Self {
                         match self {
-                            Type::Primary(x) => Type::Primary(x.clone()),
-                            Type::Secondary(x) => Type::Secondary(x.clone()),
+                            OkPat => {
+                                let v = OkVal.clone();
+                                OkNew
+                            }
+                            ErrPat => {
+                                let e = ErrVal.clone();
+                                ErrNew
+                            }
                         }
                     }
 
                     #[inline]
                     fn clone_from(&mut self, source: &Self) {
                         match (self, source) {
-                            (Type::Primary(to), Type::Primary(from)) => to.clone_from(from),
-                            (Type::Secondary(to), Type::Secondary(from)) => to.clone_from(from),
+                            (OkPat, OkFromPat) => OkVal.clone_from(OkFromVal),
+                            (ErrPat, ErrFromPat) => ErrVal.clone_from(ErrFromVal),
                             (to, from) => *to = from.clone(),
                         }
                     }
                 }
+                }
+            ));
+        }
+        if let (Some(ok_param), Some(err_param)) = (
+            ok_param.filter(Rebindable::is_bare),
+            err_param.filter(Rebindable::is_bare),
+        ) {
+            // Each deref'd type along with the bounds it needs.
+            let both = |ok: proc_macro2::TokenStream, err: proc_macro2::TokenStream| {
+                let replacements = [(ok_param.param, ok), (err_param.param, err)];
+                let generics = args.rebind_generics(&replacements);
+                let bounds = args.rebound_predicates(&replacements);
+                quote!(#typ #generics where #bounds)
+            };
+            let (ok_ref, ok_mut) = (
+                quote!(&'__s #primary_inner),
+                quote!(&'__s mut #primary_inner),
+            );
+            let (err_ref, err_mut) = (
+                quote!(&'__s #secondary_inner),
+                quote!(&'__s mut #secondary_inner),
+            );
+            let (ok_target, ok_target_mut) = (
+                quote!(&'__s <#primary_inner as ::core::ops::Deref>::Target),
+                quote!(&'__s mut <#primary_inner as ::core::ops::Deref>::Target),
+            );
+            let (err_target, err_target_mut) = (
+                quote!(&'__s <#secondary_inner as ::core::ops::Deref>::Target),
+                quote!(&'__s mut <#secondary_inner as ::core::ops::Deref>::Target),
+            );
+            result_impl = result_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_predicates: &where_predicates,
+                    T: &primary_inner,
+                    E: &secondary_inner,
+                    BothDeref: both(ok_target.clone(), err_target.clone()),
+                    BothDerefMut: both(ok_target_mut.clone(), err_target_mut.clone()),
+                    OkDeref: both(ok_target, err_ref),
+                    OkDerefMut: both(ok_target_mut, err_mut),
+                    ErrDeref: both(ok_ref, err_target),
+                    ErrDerefMut: both(ok_mut, err_target_mut),
+                },
+                {
+                    impl impl_generics Type ty_generics where where_predicates T: ::core::ops::Deref, E: ::core::ops::Deref {
+                        pub fn as_deref<'__s>(&'__s self) -> BothDeref {
+                            Type::from_result(self.as_result().map(|t| t.deref()).map_err(|e| e.deref()))
+                        }
+                    }
+
+                    impl impl_generics Type ty_generics where where_predicates T: ::core::ops::DerefMut, E: ::core::ops::DerefMut {
+                        pub fn as_deref_mut<'__s>(&'__s mut self) -> BothDerefMut {
+                            Type::from_result(self.as_result_mut().map(|t| t.deref_mut()).map_err(|e| e.deref_mut()))
+                        }
+                    }
+
+                    impl impl_generics Type ty_generics where where_predicates T: ::core::ops::Deref {
+                        pub fn as_deref_ok<'__s>(&'__s self) -> OkDeref {
+                            Type::from_result(self.as_result().map(|t| t.deref()))
+                        }
+                    }
+
+                    impl impl_generics Type ty_generics where where_predicates T: ::core::ops::DerefMut {
+                        pub fn as_deref_mut_ok<'__s>(&'__s mut self) -> OkDerefMut {
+                            Type::from_result(self.as_result_mut().map(|t| t.deref_mut()))
+                        }
+                    }
+
+                    impl impl_generics Type ty_generics where where_predicates E: ::core::ops::Deref {
+                        pub fn as_deref_err<'__s>(&'__s self) -> ErrDeref {
+                            Type::from_result(self.as_result().map_err(|e| e.deref()))
+                        }
+                    }
+
+                    impl impl_generics Type ty_generics where where_predicates E: ::core::ops::DerefMut {
+                        pub fn as_deref_mut_err<'__s>(&'__s mut self) -> ErrDerefMut {
+                            Type::from_result(self.as_result_mut().map_err(|e| e.deref_mut()))
+                        }
+                    }
+                }
+            ));
+        } else {
+            // Without both payloads to replace, dereferencing returns a std
+            // `Result` instead, like `as_deref` on `OptionLike` enums does.
+            if ok.is_single() && err.is_single() {
+                result_impl = result_impl.quote_with(smart_quote!(
+                    Vars {
+                        Type: &typ,
+                        impl_generics: &impl_generics,
+                        ty_generics: &ty_generics,
+                        where_clause: &where_clause,
+                        T: &primary_inner,
+                        E: &secondary_inner,
+                    },
+                    {
+                        impl impl_generics Type ty_generics where_clause {
+                            #[inline]
+                            pub fn as_deref(&self) -> ::core::result::Result<&<T as ::core::ops::Deref>::Target, &<E as ::core::ops::Deref>::Target>
+                            where
+                                for<'__r> T: ::core::ops::Deref,
+                                for<'__r> E: ::core::ops::Deref,
+                            {
+                                self.as_result()
+                                    .map(::core::ops::Deref::deref)
+                                    .map_err(::core::ops::Deref::deref)
+                            }
+
+                            #[inline]
+                            pub fn as_deref_mut(&mut self) -> ::core::result::Result<&mut <T as ::core::ops::Deref>::Target, &mut <E as ::core::ops::Deref>::Target>
+                            where
+                                for<'__r> T: ::core::ops::DerefMut,
+                                for<'__r> E: ::core::ops::DerefMut,
+                            {
+                                self.as_result_mut()
+                                    .map(::core::ops::DerefMut::deref_mut)
+                                    .map_err(::core::ops::DerefMut::deref_mut)
+                            }
+                        }
+                    }
+                ));
+            }
+            if ok.is_single() {
+                result_impl = result_impl.quote_with(smart_quote!(
+                    Vars {
+                        Type: &typ,
+                        impl_generics: &impl_generics,
+                        ty_generics: &ty_generics,
+                        where_clause: &where_clause,
+                        T: &primary_inner,
+                        ErrRef: err.ref_ty(None),
+                        ErrMut: err.ref_ty(Some(Default::default())),
+                    },
+                    {
+                        impl impl_generics Type ty_generics where_clause {
+                            #[inline]
+                            pub fn as_deref_ok(&self) -> ::core::result::Result<&<T as ::core::ops::Deref>::Target, ErrRef>
+                            where
+                                for<'__r> T: ::core::ops::Deref,
+                            {
+                                self.as_result().map(::core::ops::Deref::deref)
+                            }
+
+                            #[inline]
+                            pub fn as_deref_mut_ok(&mut self) -> ::core::result::Result<&mut <T as ::core::ops::Deref>::Target, ErrMut>
+                            where
+                                for<'__r> T: ::core::ops::DerefMut,
+                            {
+                                self.as_result_mut().map(::core::ops::DerefMut::deref_mut)
+                            }
+                        }
+                    }
+                ));
+            }
+            if err.is_single() {
+                result_impl = result_impl.quote_with(smart_quote!(
+                    Vars {
+                        Type: &typ,
+                        impl_generics: &impl_generics,
+                        ty_generics: &ty_generics,
+                        where_clause: &where_clause,
+                        E: &secondary_inner,
+                        OkRef: ok.ref_ty(None),
+                        OkMut: ok.ref_ty(Some(Default::default())),
+                    },
+                    {
+                        impl impl_generics Type ty_generics where_clause {
+                            #[inline]
+                            pub fn as_deref_err(&self) -> ::core::result::Result<OkRef, &<E as ::core::ops::Deref>::Target>
+                            where
+                                for<'__r> E: ::core::ops::Deref,
+                            {
+                                self.as_result().map_err(::core::ops::Deref::deref)
+                            }
+
+                            #[inline]
+                            pub fn as_deref_mut_err(&mut self) -> ::core::result::Result<OkMut, &mut <E as ::core::ops::Deref>::Target>
+                            where
+                                for<'__r> E: ::core::ops::DerefMut,
+                            {
+                                self.as_result_mut().map_err(::core::ops::DerefMut::deref_mut)
+                            }
+                        }
+                    }
+                ));
+            }
+        }
+        if let Some(referent) = reference_payload(primary_fields) {
+            result_impl = result_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_clause: &where_clause,
+                    E: &secondary_inner,
+                    OkPat: ok.pat("v"),
+                    ErrPat: err.pat("e"),
+                    ErrVal: err.value("e"),
+                    Referent: referent,
+                    ConstIfCopy: args.const_if_copy(&[secondary_fields]),
+                },
+                {
+                    impl impl_generics Type ty_generics where_clause {
+                        #[inline]
+                        pub ConstIfCopy fn copied(self) -> ::core::result::Result<Referent, E> where for<'__r> Referent: ::core::marker::Copy {
+                            match self {
+                                OkPat => ::core::result::Result::Ok(*v),
+                                ErrPat => ::core::result::Result::Err(ErrVal),
+                            }
+                        }
+
+                        #[inline]
+                        pub fn cloned(self) -> ::core::result::Result<Referent, E> where for<'__r> Referent: ::core::clone::Clone {
+                            self.into_result().map(|t| t.clone())
+                        }
+                    }
+                }
+            ));
+        }
+        if args.aliases {
+            let is_primary = args.alias(primary, "is_{}")?;
+            let is_secondary = args.alias(secondary, "is_{}")?;
+            let unwrap_primary = args.alias(primary, "unwrap_{}")?;
+            let unwrap_secondary = args.alias(secondary, "unwrap_{}")?;
+            let expect_primary = args.alias(primary, "expect_{}")?;
+            let expect_secondary = args.alias(secondary, "expect_{}")?;
+            let map_primary = args.alias(primary, "map_{}")?;
+            let map_secondary = args.alias(secondary, "map_{}")?;
+            let primary_or = args.alias(primary, "{}_or")?;
+            result_impl = result_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_predicates: &where_predicates,
+                    where_clause: &where_clause,
+                    Primary: primary,
+                    Secondary: secondary,
+                    T: &primary_inner,
+                    E: &secondary_inner,
+                    OkPat: ok.pat("v"),
+                    OkVal: ok.value("v"),
+                    ConstIfCopy: args.const_if_copy(&[primary_fields, secondary_fields]),
+                    MapOkType: &map_ok_type,
+                    MapErrType: &map_err_type,
+                    MapOkBounds: &map_ok_bounds,
+                    MapErrBounds: &map_err_bounds,
+                    MapOkBody: if ok_param.is_some_and(|p| p.is_bare()) {
+                        quote!(#typ::from_result(self.into_result().map(op)))
+                    } else {
+                        quote!(self.into_result().map(op))
+                    },
+                    MapErrBody: if err_param.is_some_and(|p| p.is_bare()) {
+                        quote!(#typ::from_result(self.into_result().map_err(op)))
+                    } else {
+                        quote!(self.into_result().map_err(op))
+                    },
+                    IsPrimary: is_primary,
+                    IsSecondary: is_secondary,
+                    UnwrapPrimary: unwrap_primary,
+                    UnwrapSecondary: unwrap_secondary,
+                    ExpectPrimary: expect_primary,
+                    ExpectSecondary: expect_secondary,
+                    MapPrimary: map_primary,
+                    MapSecondary: map_secondary,
+                    PrimaryOr: primary_or,
+                },
+                {
+                    impl impl_generics Type ty_generics where_clause {
+                        #[inline]
+                        pub const fn IsPrimary(&self) -> bool {
+                            ::core::matches!(self, Type::Primary { .. })
+                        }
+
+                        #[inline]
+                        pub const fn IsSecondary(&self) -> bool {
+                            ::core::matches!(self, Type::Secondary { .. })
+                        }
 
-                // impl<T: core::ops::Deref, E> Type<T, E> {
-                //     pub fn as_deref_ok(&self) -> Type<&T::Target, &E> {
-                //         self.as_ref().map(|t| t.deref())
-                //     }
-                // }
+                        #[inline]
+                        pub fn MapPrimary<_Other, O: ::core::ops::FnOnce(T) -> _Other>(self, op: O) -> MapOkType where MapOkBounds {
+                            MapOkBody
+                        }
+
+                        #[inline]
+                        pub fn MapSecondary<_Other, O: ::core::ops::FnOnce(E) -> _Other>(self, op: O) -> MapErrType where MapErrBounds {
+                            MapErrBody
+                        }
+
+                        #[inline]
+                        pub ConstIfCopy fn PrimaryOr(self, default: T) -> T {
+                            match self {
+                                OkPat => OkVal,
+                                Type::Secondary { .. } => default,
+                            }
+                        }
+                    }
+
+                    impl impl_generics Type ty_generics where where_predicates E: ::core::fmt::Debug {
+                        #[inline]
+                        pub fn UnwrapPrimary(self) -> T {
+                            self.into_result().unwrap()
+                        }
+
+                        #[inline]
+                        pub fn ExpectPrimary(self, msg: &str) -> T {
+                            self.into_result().expect(msg)
+                        }
+                    }
 
-                // impl<T, E: core::ops::Deref> Type<T, E> {
-                //     pub fn as_deref_err(&self) -> Type<&T, &E::Target> {
-                //         self.as_ref().map_err(|e| e.deref())
-                //     }
-                // }
+                    impl impl_generics Type ty_generics where where_predicates T: ::core::fmt::Debug {
+                        #[inline]
+                        pub fn UnwrapSecondary(self) -> E {
+                            self.into_result().unwrap_err()
+                        }
 
-                impl impl_generics Type ty_generics where where_predicates T: core::ops::Deref, E: core::ops::Deref {
-                    pub fn as_deref(&self) -> Type<&T::Target, &E::Target> {
-                        self.as_ref().map(|t| t.deref()).map_err(|e| e.deref())
+                        #[inline]
+                        pub fn ExpectSecondary(self, msg: &str) -> E {
+                            self.into_result().expect_err(msg)
+                        }
                     }
                 }
+            ));
+        }
+        if args.try_trait {
+            // The residual is the std one, so `?` converts errors through
+            // `From` between these enums and `Result` alike.
+            result_impl = result_impl.quote_with(smart_quote!(
+                Vars {
+                    Type: &typ,
+                    impl_generics: &impl_generics,
+                    ty_generics: &ty_generics,
+                    where_clause: &where_clause,
+                    where_predicates: &where_predicates,
+                    OkPat: ok.pat("v"),
+                    OkVal: ok.value("v"),
+                    OkNew: ok.construct("v"),
+                    ErrPat: err.pat("e"),
+                    ErrVal: err.value("e"),
+                    ErrNew: err.construct("e"),
+                    T: &primary_inner,
+                    E: &secondary_inner,
+                    ImplResidualG: args.impl_generics_with(impl_generics, quote!(_Residual)),
+                },
+                {
+                    impl impl_generics ::core::ops::Try for Type ty_generics where_clause {
+                        type Output = T;
+                        type Residual = ::core::result::Result<::core::convert::Infallible, E>;
+
+                        #[inline]
+                        fn from_output(v: T) -> Self {
+                            OkNew
+                        }
+
+                        #[inline]
+                        fn branch(self) -> ::core::ops::ControlFlow<Self::Residual, T> {
+                            match self {
+                                OkPat => ::core::ops::ControlFlow::Continue(OkVal),
+                                ErrPat => ::core::ops::ControlFlow::Break(::core::result::Result::Err(ErrVal)),
+                            }
+                        }
+                    }
 
-                // impl<T: core::ops::DerefMut, E> Type<T, E> {
-                //     pub fn as_deref_mut_ok(&mut self) -> Type<&mut T::Target, &mut E> {
-                //         self.as_mut().map(|t| t.deref_mut())
-                //     }
-                // }
-
-                // impl<T, E: core::ops::DerefMut> Type<T, E> {
-                //     pub fn as_deref_mut_err(&mut self) -> Type<&mut T, &mut E::Target> {
-                //         self.as_mut().map_err(|e| e.deref_mut())
-                //     }
-                // }
-
-                // impl<T: core::ops::DerefMut, E: core::ops::DerefMut> Type<T, E> {
-                //     pub fn as_deref_mut(&mut self) -> Type<&mut T::Target, &mut E::Target> {
-                //         self.as_mut()
-                //             .map(|t| t.deref_mut())
-                //             .map_err(|e| e.deref_mut())
-                //     }
-                // }
-
-
-                // flatten
-
-                // impl<'a, T, E> IntoIterator for &'a Type<T, E> {
-                //     type Item = &'a T;
-                //     type IntoIter = core::result::Iter<'a, T>;
-
-                //     fn into_iter(self) -> core::result::Iter<'a, T> {
-                //         self.into_result().iter()
-                //     }
-                // }
-
-                // impl<'a, T, E> IntoIterator for &'a mut Type<T, E> {
-                //     type Item = &'a mut T;
-                //     type IntoIter = IterMut<'a, T>;
-
-                //     fn into_iter(self) -> IterMut<'a, T> {
-                //         self.iter_mut()
-                //     }
-                // }
+                    impl ImplResidualG ::core::ops::FromResidual<::core::result::Result<::core::convert::Infallible, _Residual>> for Type ty_generics
+                    where
+                        where_predicates
+                        E: ::core::convert::From<_Residual>,
+                    {
+                        #[inline]
+                        fn from_residual(residual: ::core::result::Result<::core::convert::Infallible, _Residual>) -> Self {
+                            match residual {
+                                ::core::result::Result::Err(e) => {
+                                    let e: E = ::core::convert::From::from(e);
+                                    ErrNew
+                                }
+                            }
+                        }
+                    }
                 }
             ));
         }
-        result_impl
+        Ok(result_impl)
     }
 }
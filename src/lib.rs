@@ -34,6 +34,143 @@
 //! }
 //! ```
 //!
+//! Variants with several fields carry a tuple payload. They get the inherent
+//! methods but not the trait, since `as_option`/`as_result` can only lend a
+//! tuple of references to the fields.
+//!
+//! ```rust
+//! use result_like::OptionLike;
+//!
+//! #[derive(OptionLike)]
+//! enum Hit {
+//!     Found(&'static str, u32),
+//!     Miss,
+//! }
+//!
+//! let hit = Hit::from_option(Some(("key", 1)));
+//! assert_eq!(hit.as_option(), Some((&"key", &1)));
+//! assert_eq!(hit.map(|(_, v)| v + 1), Some(2));
+//! ```
+//!
+//! When the payload isn't a type parameter, there's no `Hit<U>` to map into,
+//! so `map`, `and_then`, `zip`, `map_err` and friends return a std `Option` or
+//! `Result`. The same goes for `as_ref`, `as_mut`, `copied`, `cloned` and the
+//! `as_deref` family, including `as_deref_ok` and `as_deref_err`, whenever a
+//! payload can't be replaced. `update` and `update_err` map the payload to
+//! the same type and keep the enum.
+//!
+//! Bounds on the payload parameter carry over to the new one, so `map` on
+//! `Sorted<T: Ord>` returns `Sorted<U>` with `U: Ord`.
+//!
+//! ```rust
+//! use result_like::OptionLike;
+//!
+//! #[derive(OptionLike, Debug, PartialEq)]
+//! enum Number {
+//!     Value(i64),
+//!     Nan,
+//! }
+//!
+//! assert_eq!(Number::Value(2).update(|v| v * 3), Number::Value(6));
+//! assert_eq!(Number::Value(2).map(|v| v as f64 / 4.0), Some(0.5));
+//! ```
+//!
+//! The first variant plays `Some`/`Ok`/`true` by default. To keep another
+//! declaration order, mark the roles with `#[option_like(some)]`,
+//! `#[option_like(none)]`, `#[result_like(ok)]`, `#[result_like(err)]`,
+//! `#[bool_like(true)]` or `#[bool_like(false)]`.
+//!
+//! ```rust
+//! use result_like::OptionLike;
+//!
+//! #[derive(OptionLike)]
+//! enum Lookup<T> {
+//!     Missing,
+//!     #[option_like(some)]
+//!     Found(T),
+//! }
+//!
+//! assert_eq!(Lookup::Found(1).into_option(), Some(1));
+//! ```
+//!
+//! Generated items can be left out with `skip(..)` or picked with `only(..)`.
+//! Methods are named as themselves and trait impls by the snake-cased trait,
+//! e.g. `clone`, `copy`, `default`, `from`, `into_iter` or `option_like`.
+//! The conversions such as `into_option` are always generated.
+//!
+//! ```rust
+//! use result_like::OptionLike;
+//!
+//! #[derive(OptionLike, Clone, Default)]
+//! #[option_like(skip(clone, default))]
+//! enum Slot<T> {
+//!     Filled(T),
+//!     #[default]
+//!     Empty,
+//! }
+//! ```
+//!
+//! Generated methods are as visible as the enum itself; `vis = "pub(super)"`
+//! overrides that.
+//!
+//! Crates re-exporting the derives can point the generated code at their own
+//! path with `crate = "my_facade::result_like"`.
+//!
+//! `aliases` adds methods named after the variants, e.g. `is_found`,
+//! `unwrap_found`, `expect_found`, `map_found` and `found_or` for a `Found`
+//! variant, or `map_failure` and `unwrap_failure` for a `Failure` variant.
+//! Acronyms count as one word, so `HTTPOn` gets `is_http_on`. An alias that
+//! repeats another generated method, like `is_some` for a `Some` variant, is
+//! a compile error.
+//!
+//! ```rust
+//! use result_like::BoolLike;
+//!
+//! #[derive(BoolLike)]
+//! #[bool_like(aliases)]
+//! enum Status {
+//!     Enabled,
+//!     Disabled,
+//! }
+//!
+//! assert!(Status::Enabled.is_enabled());
+//! ```
+//!
+//! Methods that only borrow, like `as_option`, `as_ref`, `is_some` or `take`,
+//! are `const fn`. Methods that take the value, like `unwrap`, `unwrap_or` or
+//! `into_result`, are `const fn` too when the payloads are known to be `Copy`:
+//! primitives, references, type parameters bounded by `Copy`, or types the
+//! enum's where clause bounds by `Copy`.
+//!
+//! ```rust
+//! use result_like::OptionLike;
+//!
+//! #[derive(Clone, Copy)]
+//! struct Point(i32, i32);
+//!
+//! #[derive(OptionLike)]
+//! enum Position
+//! where
+//!     Point: Copy,
+//! {
+//!     At(Point),
+//!     Nowhere,
+//! }
+//!
+//! const ORIGIN: Point = Position::Nowhere.unwrap_or(Point(0, 0));
+//! ```
+//!
+//! `?` only works on custom types through the unstable `Try` trait; the
+//! [`try_ok!`], [`try_some!`] and [`ensure_true!`] macros return early in the
+//! same way on stable.
+//!
+//! On nightly, the `nightly` feature lets enums opt into `Try` and
+//! `FromResidual` with `#[option_like(try)]` or `#[result_like(try)]`. The
+//! residuals are the std ones, so `?` also moves between those enums and
+//! [Option] or [Result], converting errors with [From]. Crates that opt in
+//! need `#![feature(try_trait_v2)]`; enums without the attribute are left
+//! alone, so enabling the feature doesn't break other crates.
+//!
 //! # BoolLike
 //!
 //! BoolLike is comparably simpler than OptionLike and ResultLike.
@@ -74,118 +211,618 @@ extern crate result_like_derive;
 
 pub use result_like_derive::*;
 
+/// Common interface of [bool]-like enums.
+///
+/// `#[derive(BoolLike)]` implements the conversions and constants.
+/// [`to_bool`](BoolLike::to_bool) takes `&self` so generic code does not need
+/// the enum to be `Copy`.
 pub trait BoolLike
 where
     Self: Sized,
 {
+    const TRUE: Self;
+    const FALSE: Self;
+
+    fn to_bool(&self) -> bool;
+    fn from_bool(value: bool) -> Self;
+
+    #[inline]
+    fn then_some<T>(self, t: T) -> Option<T> {
+        self.to_bool().then_some(t)
+    }
+
+    #[inline]
+    fn then<T, F: FnOnce() -> T>(self, f: F) -> Option<T> {
+        self.to_bool().then(f)
+    }
 }
 
+/// Common interface of [Option]-like enums.
+///
+/// `#[derive(OptionLike)]` implements the conversion methods. The rest of the
+/// [Option] API is provided on top of them, so generic code can be written
+/// once for every option-shaped enum.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` doesn't implement `OptionLike`",
+    note = "a derived enum implements it only when its some variant has a single field; \
+            otherwise convert it with the inherent `into_option()` first"
+)]
 pub trait OptionLike
 where
     Self: Sized,
 {
     type SomeType;
+    /// The same enum carrying `U` instead, or [Option] when the payload is not
+    /// an unbounded type parameter of the enum; `U` can't be required to meet
+    /// the bounds here.
+    ///
+    /// A bound on a shorthand projection such as `T::Item: Debug` can't be
+    /// rewritten for another type, so such enums keep the std-returning
+    /// methods throughout. Spell it `<T as Iterator>::Item: Debug` to get the
+    /// enum back from `map`.
+    type Rebind<U>: OptionLike<SomeType = U>;
+
+    fn from_option(option: Option<Self::SomeType>) -> Self;
+    fn into_option(self) -> Option<Self::SomeType>;
+    fn as_option(&self) -> Option<&Self::SomeType>;
+    fn as_option_mut(&mut self) -> Option<&mut Self::SomeType>;
+
+    #[inline]
+    fn from_value(value: Self::SomeType) -> Self {
+        Self::from_option(Some(value))
+    }
+
+    /// Converts another option-like value with the same payload into `Self`.
+    #[inline]
+    fn convert_from<O: OptionLike<SomeType = Self::SomeType>>(other: O) -> Self {
+        Self::from_option(other.into_option())
+    }
+
+    /// Converts `self` into another option-like type with the same payload.
+    #[inline]
+    fn convert<O: OptionLike<SomeType = Self::SomeType>>(self) -> O {
+        O::from_option(self.into_option())
+    }
+
+    #[inline]
+    fn is_some(&self) -> bool {
+        self.as_option().is_some()
+    }
+
+    #[inline]
+    fn is_none(&self) -> bool {
+        self.as_option().is_none()
+    }
+
+    #[inline]
+    fn expect(self, msg: &str) -> Self::SomeType {
+        self.into_option().expect(msg)
+    }
+
+    #[inline]
+    fn unwrap(self) -> Self::SomeType {
+        self.into_option().unwrap()
+    }
+
+    #[inline]
+    fn unwrap_or(self, default: Self::SomeType) -> Self::SomeType {
+        self.into_option().unwrap_or(default)
+    }
+
+    #[inline]
+    fn unwrap_or_else<_Function: FnOnce() -> Self::SomeType>(self, f: _Function) -> Self::SomeType {
+        self.into_option().unwrap_or_else(f)
+    }
+
+    #[inline]
+    fn unwrap_or_default(self) -> Self::SomeType
+    where
+        Self::SomeType: Default,
+    {
+        self.into_option().unwrap_or_default()
+    }
 
-    // fn from_value(value: Self::SomeType) -> Self;
-    // fn from_option(option: Option<Self::SomeType>) -> Self;
-    // fn into_option(self) -> Option<Self::SomeType>;
-    // fn as_option(&self) -> Option<&Self::SomeType>;
-    // fn as_option_mut(&mut self) -> Option<&mut Self::SomeType>;
-    // fn get_or_insert_with<_Function: FnOnce() -> Self::SomeType>(
-    //     &mut self,
-    //     f: _Function,
-    // ) -> &mut Self::SomeType;
-
-    // #[inline]
-    // fn expect(self, msg: &str) -> Self::SomeType where {
-    //     self.into_option().expect(msg)
-    // }
-
-    // #[inline]
-    // fn unwrap(self) -> Self::SomeType {
-    //     self.into_option().unwrap()
-    // }
-
-    // #[inline]
-    // fn unwrap_or(self, default: Self::SomeType) -> Self::SomeType {
-    //     self.into_option().unwrap_or(default)
-    // }
-
-    // #[inline]
-    // fn unwrap_or_else<_Function: FnOnce() -> Self::SomeType>(self, f: _Function) -> Self::SomeType {
-    //     self.into_option().unwrap_or_else(f)
-    // }
-
-    // #[inline]
-    // fn ok_or<_Error>(self, err: _Error) -> Result<Self::SomeType, _Error> {
-    //     self.into_option().ok_or(err)
-    // }
-
-    // #[inline]
-    // fn ok_or_else<_Error, _Function: FnOnce() -> _Error>(
-    //     self,
-    //     err: _Function,
-    // ) -> Result<Self::SomeType, _Error> {
-    //     self.into_option().ok_or_else(err)
-    // }
-
-    // #[inline]
-    // fn filter<P: FnOnce(&Self::SomeType) -> bool>(self, predicate: P) -> Self {
-    //     Self::from_option(self.into_option().filter(predicate))
-    // }
-
-    // #[inline]
-    // fn or(self, optb: Self) -> Self {
-    //     Self::from_option(self.into_option().or(optb.into_option()))
-    // }
-
-    // #[inline]
-    // fn or_else<_Function: FnOnce() -> Self>(self, f: _Function) -> Self {
-    //     Self::from_option(self.into_option().or_else(|| f().into_option()))
-    // }
-
-    // #[inline]
-    // fn map_or<_Other, _Function: FnOnce(Self::SomeType) -> _Other>(
-    //     self,
-    //     default: _Other,
-    //     f: _Function,
-    // ) -> _Other {
-    //     self.into_option().map_or(default, f)
-    // }
-
-    // #[inline]
-    // fn xor(self, optb: Self) -> Self {
-    //     Self::from_option(self.into_option().xor(optb.into_option()))
-    // }
-
-    // #[inline]
-    // fn get_or_insert(&mut self, v: Self::SomeType) -> &mut Self::SomeType {
-    //     self.get_or_insert_with(|| v)
-    // }
-
-    // #[inline]
-    // fn take(&mut self) -> Self
-    // where
-    //     Self: Default,
-    // {
-    //     core::mem::take(self)
-    // }
-
-    // #[inline]
-    // fn replace(&mut self, value: Self::SomeType) -> Self {
-    //     core::mem::replace(self, Self::from_value(value))
-    // }
-
-    // #[inline]
-    // fn unwrap_or_default(self) -> Self::SomeType
-    // where
-    //     Self::SomeType: Default,
-    // {
-    //     self.into_option().unwrap_or_default()
-    // }
+    #[inline]
+    fn ok_or<_Error>(self, err: _Error) -> Result<Self::SomeType, _Error> {
+        self.into_option().ok_or(err)
+    }
+
+    #[inline]
+    fn ok_or_else<_Error, _Function: FnOnce() -> _Error>(
+        self,
+        err: _Function,
+    ) -> Result<Self::SomeType, _Error> {
+        self.into_option().ok_or_else(err)
+    }
+
+    #[inline]
+    fn filter<P: FnOnce(&Self::SomeType) -> bool>(self, predicate: P) -> Self {
+        Self::from_option(self.into_option().filter(predicate))
+    }
+
+    #[inline]
+    fn or(self, optb: Self) -> Self {
+        Self::from_option(self.into_option().or(optb.into_option()))
+    }
+
+    #[inline]
+    fn or_else<_Function: FnOnce() -> Self>(self, f: _Function) -> Self {
+        Self::from_option(self.into_option().or_else(|| f().into_option()))
+    }
+
+    #[inline]
+    fn xor(self, optb: Self) -> Self {
+        Self::from_option(self.into_option().xor(optb.into_option()))
+    }
+
+    #[inline]
+    fn map_or<_Other, _Function: FnOnce(Self::SomeType) -> _Other>(
+        self,
+        default: _Other,
+        f: _Function,
+    ) -> _Other {
+        self.into_option().map_or(default, f)
+    }
+
+    #[inline]
+    fn map_or_else<
+        _Other,
+        _Default: FnOnce() -> _Other,
+        _Function: FnOnce(Self::SomeType) -> _Other,
+    >(
+        self,
+        default: _Default,
+        f: _Function,
+    ) -> _Other {
+        self.into_option().map_or_else(default, f)
+    }
+
+    #[inline]
+    fn map<U, _Function: FnOnce(Self::SomeType) -> U>(self, f: _Function) -> Self::Rebind<U> {
+        Self::Rebind::<U>::from_option(self.into_option().map(f))
+    }
+
+    #[inline]
+    fn and<U>(self, optb: Self::Rebind<U>) -> Self::Rebind<U> {
+        Self::Rebind::<U>::from_option(self.into_option().and(optb.into_option()))
+    }
+
+    #[inline]
+    fn and_then<U, _Function: FnOnce(Self::SomeType) -> Self::Rebind<U>>(
+        self,
+        f: _Function,
+    ) -> Self::Rebind<U> {
+        match self.into_option() {
+            Some(v) => f(v),
+            None => Self::Rebind::<U>::from_option(None),
+        }
+    }
+
+    #[inline]
+    fn zip<U>(self, other: Self::Rebind<U>) -> Self::Rebind<(Self::SomeType, U)> {
+        Self::Rebind::<(Self::SomeType, U)>::from_option(
+            self.into_option().zip(other.into_option()),
+        )
+    }
+
+    #[inline]
+    fn iter(&self) -> core::option::IntoIter<&Self::SomeType> {
+        self.as_option().into_iter()
+    }
+
+    #[inline]
+    fn iter_mut(&mut self) -> core::option::IntoIter<&mut Self::SomeType> {
+        self.as_option_mut().into_iter()
+    }
+
+    #[inline]
+    fn get_or_insert(&mut self, v: Self::SomeType) -> &mut Self::SomeType {
+        self.get_or_insert_with(|| v)
+    }
+
+    #[inline]
+    fn get_or_insert_with<_Function: FnOnce() -> Self::SomeType>(
+        &mut self,
+        f: _Function,
+    ) -> &mut Self::SomeType {
+        if self.is_none() {
+            *self = Self::from_value(f());
+        }
+        match self.as_option_mut() {
+            Some(v) => v,
+            None => unreachable!(),
+        }
+    }
+
+    #[inline]
+    fn take(&mut self) -> Self {
+        core::mem::replace(self, Self::from_option(None))
+    }
+
+    #[inline]
+    fn replace(&mut self, value: Self::SomeType) -> Self {
+        core::mem::replace(self, Self::from_value(value))
+    }
 }
 
-pub trait ResultLike {
+/// Common interface of [Result]-like enums.
+///
+/// `#[derive(ResultLike)]` implements the conversion methods. The combinators
+/// below are provided on top of them, so generic code can be written once for
+/// every result-shaped enum.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` doesn't implement `ResultLike`",
+    note = "a derived enum implements it only when both variants have a single field; \
+            otherwise convert it with the inherent `into_result()` first"
+)]
+pub trait ResultLike
+where
+    Self: Sized,
+{
     type OkType;
     type ErrType;
+    /// The same enum carrying `U` as its ok payload, or [Result] when the ok
+    /// payload is not an unbounded type parameter of the enum.
+    type RebindOk<U>: ResultLike<OkType = U, ErrType = Self::ErrType>;
+    /// The same enum carrying `F` as its err payload, or [Result] when the err
+    /// payload is not an unbounded type parameter of the enum.
+    type RebindErr<F>: ResultLike<OkType = Self::OkType, ErrType = F>;
+
+    fn from_result(result: Result<Self::OkType, Self::ErrType>) -> Self;
+    fn into_result(self) -> Result<Self::OkType, Self::ErrType>;
+    fn as_result(&self) -> Result<&Self::OkType, &Self::ErrType>;
+    fn as_result_mut(&mut self) -> Result<&mut Self::OkType, &mut Self::ErrType>;
+
+    #[inline]
+    fn from_ok(value: Self::OkType) -> Self {
+        Self::from_result(Ok(value))
+    }
+
+    #[inline]
+    fn from_err(err: Self::ErrType) -> Self {
+        Self::from_result(Err(err))
+    }
+
+    /// Converts another result-like value with the same payloads into `Self`.
+    #[inline]
+    fn convert_from<R: ResultLike<OkType = Self::OkType, ErrType = Self::ErrType>>(
+        other: R,
+    ) -> Self {
+        Self::from_result(other.into_result())
+    }
+
+    /// Converts `self` into another result-like type with the same payloads.
+    #[inline]
+    fn convert<R: ResultLike<OkType = Self::OkType, ErrType = Self::ErrType>>(self) -> R {
+        R::from_result(self.into_result())
+    }
+
+    #[inline]
+    fn is_ok(&self) -> bool {
+        self.as_result().is_ok()
+    }
+
+    #[inline]
+    fn is_err(&self) -> bool {
+        self.as_result().is_err()
+    }
+
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_ok_and<F: FnOnce(Self::OkType) -> bool>(self, f: F) -> bool {
+        self.into_result().is_ok_and(f)
+    }
+
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_err_and<F: FnOnce(Self::ErrType) -> bool>(self, f: F) -> bool {
+        self.into_result().is_err_and(f)
+    }
+
+    #[inline]
+    fn ok(self) -> Option<Self::OkType> {
+        self.into_result().ok()
+    }
+
+    #[inline]
+    fn err(self) -> Option<Self::ErrType> {
+        self.into_result().err()
+    }
+
+    #[inline]
+    fn map_or<U, F: FnOnce(Self::OkType) -> U>(self, default: U, f: F) -> U {
+        self.into_result().map_or(default, f)
+    }
+
+    #[inline]
+    fn map_or_else<U, D: FnOnce(Self::ErrType) -> U, F: FnOnce(Self::OkType) -> U>(
+        self,
+        default: D,
+        f: F,
+    ) -> U {
+        self.into_result().map_or_else(default, f)
+    }
+
+    #[inline]
+    fn map<U, F: FnOnce(Self::OkType) -> U>(self, op: F) -> Self::RebindOk<U> {
+        Self::RebindOk::<U>::from_result(self.into_result().map(op))
+    }
+
+    #[inline]
+    fn map_err<F, O: FnOnce(Self::ErrType) -> F>(self, op: O) -> Self::RebindErr<F> {
+        Self::RebindErr::<F>::from_result(self.into_result().map_err(op))
+    }
+
+    #[inline]
+    fn and<U>(self, res: Self::RebindOk<U>) -> Self::RebindOk<U> {
+        match self.into_result() {
+            Ok(_) => res,
+            Err(e) => Self::RebindOk::<U>::from_err(e),
+        }
+    }
+
+    #[inline]
+    fn and_then<U, F: FnOnce(Self::OkType) -> Self::RebindOk<U>>(self, op: F) -> Self::RebindOk<U> {
+        match self.into_result() {
+            Ok(t) => op(t),
+            Err(e) => Self::RebindOk::<U>::from_err(e),
+        }
+    }
+
+    #[inline]
+    fn or<F>(self, res: Self::RebindErr<F>) -> Self::RebindErr<F> {
+        match self.into_result() {
+            Ok(v) => Self::RebindErr::<F>::from_ok(v),
+            Err(_) => res,
+        }
+    }
+
+    #[inline]
+    fn or_else<F, O: FnOnce(Self::ErrType) -> Self::RebindErr<F>>(
+        self,
+        op: O,
+    ) -> Self::RebindErr<F> {
+        match self.into_result() {
+            Ok(t) => Self::RebindErr::<F>::from_ok(t),
+            Err(e) => op(e),
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> core::option::IntoIter<&Self::OkType> {
+        self.as_result().ok().into_iter()
+    }
+
+    #[inline]
+    fn iter_mut(&mut self) -> core::option::IntoIter<&mut Self::OkType> {
+        self.as_result_mut().ok().into_iter()
+    }
+
+    #[inline]
+    fn expect(self, msg: &str) -> Self::OkType
+    where
+        Self::ErrType: core::fmt::Debug,
+    {
+        self.into_result().expect(msg)
+    }
+
+    #[inline]
+    fn unwrap(self) -> Self::OkType
+    where
+        Self::ErrType: core::fmt::Debug,
+    {
+        self.into_result().unwrap()
+    }
+
+    #[inline]
+    fn expect_err(self, msg: &str) -> Self::ErrType
+    where
+        Self::OkType: core::fmt::Debug,
+    {
+        self.into_result().expect_err(msg)
+    }
+
+    #[inline]
+    fn unwrap_err(self) -> Self::ErrType
+    where
+        Self::OkType: core::fmt::Debug,
+    {
+        self.into_result().unwrap_err()
+    }
+
+    #[inline]
+    fn unwrap_or(self, default: Self::OkType) -> Self::OkType {
+        self.into_result().unwrap_or(default)
+    }
+
+    #[inline]
+    fn unwrap_or_else<F: FnOnce(Self::ErrType) -> Self::OkType>(self, op: F) -> Self::OkType {
+        self.into_result().unwrap_or_else(op)
+    }
+
+    #[inline]
+    fn unwrap_or_default(self) -> Self::OkType
+    where
+        Self::OkType: Default,
+    {
+        self.into_result().unwrap_or_default()
+    }
+}
+
+impl BoolLike for bool {
+    const TRUE: Self = true;
+    const FALSE: Self = false;
+
+    #[inline]
+    fn to_bool(&self) -> bool {
+        *self
+    }
+
+    #[inline]
+    fn from_bool(value: bool) -> Self {
+        value
+    }
+}
+
+impl<T> OptionLike for Option<T> {
+    type SomeType = T;
+    type Rebind<U> = Option<U>;
+
+    #[inline]
+    fn from_option(option: Option<T>) -> Self {
+        option
+    }
+
+    #[inline]
+    fn into_option(self) -> Option<T> {
+        self
+    }
+
+    #[inline]
+    fn as_option(&self) -> Option<&T> {
+        self.as_ref()
+    }
+
+    #[inline]
+    fn as_option_mut(&mut self) -> Option<&mut T> {
+        self.as_mut()
+    }
+}
+
+impl<T, E> ResultLike for Result<T, E> {
+    type OkType = T;
+    type ErrType = E;
+    type RebindOk<U> = Result<U, E>;
+    type RebindErr<F> = Result<T, F>;
+
+    #[inline]
+    fn from_result(result: Result<T, E>) -> Self {
+        result
+    }
+
+    #[inline]
+    fn into_result(self) -> Result<T, E> {
+        self
+    }
+
+    #[inline]
+    fn as_result(&self) -> Result<&T, &E> {
+        self.as_ref()
+    }
+
+    #[inline]
+    fn as_result_mut(&mut self) -> Result<&mut T, &mut E> {
+        self.as_mut()
+    }
+}
+
+/// Unwraps the ok payload of a [ResultLike] value or returns early with its
+/// error, like `?` does for [Result].
+///
+/// The error goes through [From] into the error type of the enclosing
+/// function, which may return any [ResultLike] type, derived or [Result].
+/// Enums with a multi-field variant don't implement the trait, so pass them
+/// through their inherent `into_result()` first.
+///
+/// ```rust
+/// use result_like::{ResultLike, try_ok};
+///
+/// #[derive(ResultLike, Debug, PartialEq)]
+/// enum Outcome<T, E> {
+///     Done(T),
+///     Failed(E),
+/// }
+///
+/// fn parse(s: &str) -> Outcome<u8, String> {
+///     let n: u8 = try_ok!(s.parse::<u8>().map_err(|e| e.to_string()));
+///     Outcome::Done(n * 2)
+/// }
+///
+/// fn total(a: &str, b: &str) -> Result<u16, String> {
+///     Ok(u16::from(try_ok!(parse(a))) + u16::from(try_ok!(parse(b))))
+/// }
+///
+/// assert_eq!(parse("4"), Outcome::Done(8));
+/// assert_eq!(total("1", "2"), Ok(6));
+/// assert!(total("1", "x").is_err());
+/// ```
+#[macro_export]
+macro_rules! try_ok {
+    ($expr:expr $(,)?) => {
+        match $crate::ResultLike::into_result($expr) {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(error) => {
+                return $crate::ResultLike::from_result(::core::result::Result::Err(
+                    ::core::convert::From::from(error),
+                ));
+            }
+        }
+    };
+}
+
+/// Unwraps the some payload of an [OptionLike] value or returns early with the
+/// none variant of the enclosing function's [OptionLike] return type.
+/// Enums with a multi-field variant don't implement the trait, so pass them
+/// through their inherent `into_option()` first.
+///
+/// ```rust
+/// use result_like::{OptionLike, try_some};
+///
+/// #[derive(OptionLike, Debug, PartialEq)]
+/// enum Lookup<T> {
+///     Found(T),
+///     Missing,
+/// }
+///
+/// fn first_even(items: &[u8]) -> Lookup<u8> {
+///     let first = try_some!(items.first());
+///     Lookup::from_option((first % 2 == 0).then_some(*first))
+/// }
+///
+/// assert_eq!(first_even(&[2, 3]), Lookup::Found(2));
+/// assert_eq!(first_even(&[]), Lookup::Missing);
+/// ```
+#[macro_export]
+macro_rules! try_some {
+    ($expr:expr $(,)?) => {
+        match $crate::OptionLike::into_option($expr) {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => {
+                return $crate::OptionLike::from_option(::core::option::Option::None);
+            }
+        }
+    };
+}
+
+/// Returns early with `err` unless the [BoolLike] `flag` is true.
+///
+/// Like [`try_ok!`], the error goes through [From] into the error type of
+/// the enclosing function's [ResultLike] return type.
+///
+/// ```rust
+/// use result_like::{BoolLike, ensure_true};
+///
+/// #[derive(BoolLike, Clone, Copy)]
+/// enum Access {
+///     Granted,
+///     Denied,
+/// }
+///
+/// fn read(access: Access) -> Result<&'static str, String> {
+///     ensure_true!(access, "access denied");
+///     Ok("secret")
+/// }
+///
+/// assert_eq!(read(Access::Granted), Ok("secret"));
+/// assert_eq!(read(Access::Denied), Err("access denied".to_owned()));
+/// ```
+#[macro_export]
+macro_rules! ensure_true {
+    ($flag:expr, $err:expr $(,)?) => {
+        if !$crate::BoolLike::to_bool(&$flag) {
+            return $crate::ResultLike::from_result(::core::result::Result::Err(
+                ::core::convert::From::from($err),
+            ));
+        }
+    };
 }
@@ -0,0 +1,95 @@
+use result_like::BoolLike;
+
+#[test]
+fn test_bool() {
+    #[derive(BoolLike, Clone, Copy, Debug, PartialEq, Eq)]
+    enum Status {
+        Enabled,
+        Disabled,
+    }
+
+    assert!(Status::Enabled.to_bool());
+    assert_eq!(Status::from_bool(false), Status::Disabled);
+    assert_eq!(Status::Disabled.then_some(1), None);
+
+    let b: bool = Status::Enabled.into();
+    assert!(b);
+}
+
+#[test]
+fn test_trait() {
+    #[derive(BoolLike, Debug, PartialEq, Eq)]
+    enum Flag {
+        On,
+        Off,
+    }
+
+    fn count_enabled<B: BoolLike>(flags: &[B]) -> usize {
+        flags.iter().filter(|f| f.to_bool()).count()
+    }
+
+    assert_eq!(count_enabled(&[Flag::On, Flag::Off, Flag::On]), 2);
+    assert_eq!(count_enabled(&[true, false]), 1);
+    assert_eq!(<Flag as BoolLike>::TRUE, Flag::On);
+    assert_eq!(<Flag as BoolLike>::FALSE, Flag::Off);
+    assert_eq!(<Flag as BoolLike>::from_bool(false), Flag::Off);
+    assert_eq!(BoolLike::then(Flag::On, || 1), Some(1));
+}
+
+#[test]
+fn test_role_attribute() {
+    #[derive(BoolLike, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    enum Status {
+        Disabled,
+        #[bool_like(true)]
+        Enabled,
+    }
+
+    assert!(Status::Enabled.to_bool());
+    assert_eq!(Status::from_bool(false), Status::Disabled);
+    assert!(Status::Disabled < Status::Enabled);
+
+    #[derive(BoolLike, Clone, Copy, Debug, PartialEq, Eq)]
+    enum Mode {
+        #[bool_like(false)]
+        Off,
+        On,
+    }
+    assert_eq!(<Mode as BoolLike>::TRUE, Mode::On);
+}
+
+#[test]
+fn test_aliases() {
+    #[derive(BoolLike, Clone, Copy)]
+    #[bool_like(aliases)]
+    enum Status {
+        Enabled,
+        Disabled,
+    }
+
+    const { assert!(Status::Enabled.is_enabled()) };
+    assert!(Status::Disabled.is_disabled());
+    assert!(!Status::Disabled.is_enabled());
+
+    #[derive(BoolLike)]
+    #[bool_like(aliases)]
+    enum Link {
+        HTTPOn,
+        r#Off,
+    }
+
+    assert!(Link::HTTPOn.is_http_on());
+    assert!(Link::Off.is_off());
+}
+
+#[test]
+fn test_predicates() {
+    #[derive(BoolLike)]
+    enum Flag {
+        Up,
+        Down,
+    }
+
+    const _: () = assert!(Flag::Up.is_true() && Flag::Down.is_false());
+    assert!(!Flag::Up.is_false());
+}
@@ -0,0 +1,206 @@
+use result_like::{OptionLike, ResultLike};
+use std::{collections::BTreeSet, fmt::Debug, hash::Hash, pin::Pin};
+
+#[derive(OptionLike, Debug, PartialEq)]
+enum Sorted<T: Ord> {
+    Items(BTreeSet<T>),
+    Empty,
+}
+
+#[derive(OptionLike, Debug, PartialEq)]
+enum Hashed<T>
+where
+    T: Hash + Eq,
+{
+    Key(T),
+    Absent,
+}
+
+#[derive(OptionLike, Debug, PartialEq)]
+enum Ranked<K, V>
+where
+    K: Ord + Debug,
+    V: Clone,
+{
+    Entry(K, V),
+    Vacant,
+}
+
+#[derive(OptionLike, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Bounded<T: Ord>
+where
+    T: Hash,
+{
+    Value(T),
+    Nothing,
+}
+
+#[derive(OptionLike, Debug, PartialEq)]
+enum Defaulted<T = u8> {
+    Set(T),
+    Unset,
+}
+
+// No trailing comma after the last predicate.
+#[rustfmt::skip]
+#[derive(ResultLike, Debug, PartialEq)]
+enum Checked<T: Ord, E> where E: Debug {
+    Valid(T),
+    Invalid(E),
+}
+
+#[derive(ResultLike, Debug, PartialEq)]
+enum Tagged<T, E: Clone + Default> {
+    Value(T),
+    Tag(E),
+}
+
+#[derive(ResultLike, Debug, PartialEq)]
+enum Layered<T, E = String>
+where
+    Vec<T>: Clone,
+{
+    Up(T),
+    Down(E),
+}
+
+// `T::Item` can't be rewritten for another `T`, so these keep `T`.
+#[derive(OptionLike, Debug, PartialEq)]
+enum Items<T>
+where
+    T: Iterator,
+    T::Item: Debug,
+{
+    Some(T),
+    None,
+}
+
+#[derive(OptionLike, Debug, PartialEq)]
+enum Qualified<T>
+where
+    T: Iterator,
+    <T as Iterator>::Item: Debug,
+{
+    Some(T),
+    None,
+}
+
+#[test]
+fn test_inline_bounds() {
+    let sorted = Sorted::from_option(Some(BTreeSet::from([2, 1])));
+    assert_eq!(sorted.as_option().map(|s| s.len()), Some(2));
+    assert_eq!(Sorted::<u8>::default(), Sorted::Empty);
+    assert_eq!(Sorted::<u8>::Empty.unwrap_or_default(), BTreeSet::new());
+
+    let checked: Checked<u8, &str> = Checked::Valid(1);
+    assert_eq!(checked.as_result(), Ok(&1));
+    assert_eq!(checked.into_iter().collect::<Vec<_>>(), [1]);
+    assert_eq!(Checked::<u8, &str>::Invalid("no").unwrap_err(), "no");
+
+    let tagged: Tagged<u8, String> = Tagged::Value(2);
+    assert_eq!(tagged.map(u16::from), Tagged::Value(2u16));
+    let tagged: Tagged<u8, String> = Tagged::Value(2);
+    assert_eq!(tagged.and_then(|v| Tagged::Value(v * 2)), Tagged::Value(4));
+    assert_eq!(Tagged::<u8, String>::Tag("t".to_owned()).unwrap_or(0), 0);
+}
+
+#[test]
+fn test_where_clauses() {
+    let key = Hashed::Key("k");
+    assert_eq!(key.as_option(), Some(&"k"));
+    assert_eq!(Hashed::<&str>::default(), Hashed::Absent);
+    assert_eq!(key.map(str::len), Hashed::Key(1));
+    assert_eq!(Hashed::Key("k").as_ref().copied(), Hashed::Key("k"));
+    let mut taken = Hashed::Key(1);
+    assert_eq!(taken.take(), Hashed::Key(1));
+    assert_eq!(taken.into_iter().count(), 0);
+
+    let entry = Ranked::Entry(1, "v");
+    assert_eq!(entry.as_option(), Some((&1, &"v")));
+    assert_eq!(Ranked::<u8, u8>::default(), Ranked::Vacant);
+
+    let layered: Layered<u8> = Layered::Up(1);
+    assert_eq!(layered.map_err(|e| e.len()), Layered::Up(1));
+    let layered: Layered<u8> = Layered::Up(1);
+    assert_eq!(layered.map(u16::from), Layered::Up(1u16));
+    assert_eq!(Layered::<u8>::Up(1).unwrap_or_default(), 1);
+}
+
+#[test]
+fn test_default_params() {
+    let set = Defaulted::Set(3);
+    assert_eq!(set.map(u16::from), Defaulted::Set(3u16));
+    assert_eq!(Defaulted::<u8>::Unset.or(set), Defaulted::Set(3));
+    let unset: Defaulted = Defaulted::default();
+    assert_eq!(unset.unwrap_or(1), 1);
+
+    let down: Layered<u8> = Layered::Down("e".to_owned());
+    assert_eq!(down.as_result(), Err(&"e".to_owned()));
+    assert_eq!(down.into_iter().count(), 0);
+}
+
+#[test]
+fn test_rebound_bounds() {
+    let mut value = Bounded::Value(2);
+    assert_eq!(value.as_ref(), Bounded::Value(&2));
+    assert_eq!(value.as_ref().copied(), Bounded::Value(2));
+    assert_eq!(value.as_mut().cloned(), Bounded::Value(2));
+    assert_eq!(Bounded::from(&value), Bounded::Value(&2));
+    if let Bounded::Value(v) = value.as_mut() {
+        *v = 3;
+    }
+    assert_eq!(value.map(|v| v * 2), Bounded::Value(6));
+    assert_eq!(value.and_then(|v| Bounded::Value([v])), Bounded::Value([3]));
+    assert_eq!(value.zip(Bounded::Value('x')), Bounded::Value((3, 'x')));
+    assert_eq!(
+        value.zip_with(Bounded::Value(1), |a, b| a - b),
+        Bounded::Value(2)
+    );
+    assert_eq!(
+        Bounded::Value((1, 'a')).unzip(),
+        (Bounded::Value(1), Bounded::Value('a'))
+    );
+    assert_eq!(
+        Bounded::Value(Bounded::Value(1)).flatten(),
+        Bounded::Value(1)
+    );
+    assert_eq!(
+        Bounded::Value(Ok::<u8, ()>(1)).transpose(),
+        Ok(Bounded::Value(1))
+    );
+    assert_eq!(
+        Bounded::Value("s".to_owned()).as_deref(),
+        Bounded::Value("s")
+    );
+    let pinned = Pin::new(&value);
+    assert_eq!(pinned.as_pin_ref(), Bounded::Value(Pin::new(&3)));
+    assert!(Bounded::Value(1) < Bounded::Value(2));
+
+    let checked: Checked<u8, &str> = Checked::Valid(1);
+    assert_eq!(checked.as_ref(), Checked::Valid(&1));
+    assert_eq!(checked.map(|v| [v]), Checked::Valid([1]));
+    assert_eq!(checked.as_ref().copied(), Checked::Valid(1));
+    assert_eq!(
+        Checked::<Option<u8>, &str>::Valid(Some(1)).transpose(),
+        Some(Checked::Valid(1))
+    );
+    assert_eq!(
+        Checked::<u8, &str>::Invalid("e").map_err(str::len),
+        Checked::Invalid(1)
+    );
+}
+
+#[test]
+fn test_projection_bounds() {
+    let mut items = Items::Some(0..2);
+    assert_eq!(items.as_ref(), Some(&(0..2)));
+    assert_eq!(items.as_mut().and_then(Iterator::next), Some(0));
+    assert_eq!(items.map(Iterator::count), Some(1));
+    assert_eq!(Items::<std::ops::Range<u8>>::None.map(|r| r.len()), None);
+
+    let reversed: Qualified<_> = Qualified::Some(0..2).map(Iterator::rev);
+    assert_eq!(
+        reversed.into_option().map(Iterator::collect::<Vec<_>>),
+        Some(vec![1, 0])
+    );
+}
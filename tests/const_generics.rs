@@ -0,0 +1,83 @@
+use result_like::{BoolLike, OptionLike, ResultLike};
+
+#[derive(OptionLike, Debug, PartialEq)]
+enum Frame<const N: usize> {
+    Full([u8; N]),
+    Empty,
+}
+
+#[derive(OptionLike, Debug, PartialEq)]
+enum Slot<T, const N: usize> {
+    Some([T; N]),
+    None,
+}
+
+#[derive(OptionLike, Debug, PartialEq)]
+enum Tagged<T, const N: usize> {
+    Some(T),
+    None,
+}
+
+#[derive(ResultLike, Debug, PartialEq)]
+enum Packet<T, E, const N: usize> {
+    Data([T; N]),
+    Error(E),
+}
+
+#[derive(BoolLike, Clone, Copy, Debug, PartialEq)]
+enum Bit<const N: usize> {
+    Set,
+    Clear,
+}
+
+#[test]
+fn test_array_length() {
+    let frame = Frame::Full([1, 2]);
+    assert_eq!(frame.as_option(), Some(&[1, 2]));
+    assert_eq!(Frame::<4>::default(), Frame::Empty);
+    assert_eq!(Frame::<2>::from([3, 4]).unwrap(), [3, 4]);
+}
+
+#[test]
+fn test_array_payload() {
+    let mut slot = Slot::Some([1u8, 2, 3]);
+    assert_eq!(slot.as_ref(), Slot::Some([&1, &2, &3]));
+    assert_eq!(slot.as_ref().copied(), slot);
+    assert_eq!(slot.as_ref().cloned(), slot);
+    if let Slot::Some([first, ..]) = slot.as_mut() {
+        *first = 5;
+    }
+    assert_eq!(slot.map(|a| a.map(u16::from)), Slot::Some([5u16, 2, 3]));
+    assert_eq!(
+        Slot::Some(["a"; 2]).and_then(|[a, b]| Slot::Some([a.len(), b.len()])),
+        Slot::Some([1, 1])
+    );
+    assert_eq!(Slot::<u8, 2>::None.map(|a| a.map(u16::from)), Slot::None);
+    let copy: Slot<u8, 1> = Slot::Some([1]);
+    let moved = copy;
+    assert_eq!(copy, moved);
+    assert_eq!(OptionLike::map(Slot::Some([1, 2]), |[a, b]| a + b), Some(3));
+}
+
+#[test]
+fn test_const_params_kept() {
+    let tagged: Tagged<u8, 4> = Tagged::Some(1);
+    let mapped: Tagged<u16, 4> = tagged.map(u16::from);
+    assert_eq!(mapped, Tagged::Some(1));
+    let borrowed: Tagged<&u8, 4> = tagged.as_ref();
+    assert_eq!(borrowed.copied(), tagged);
+
+    let packet: Packet<u8, &str, 2> = Packet::Data([1, 2]);
+    assert_eq!(packet.as_ref(), Packet::Data([&1, &2]));
+    assert_eq!(packet.map(|a| a.map(u16::from)), Packet::Data([1u16, 2]));
+    assert_eq!(packet.map_err(str::len), Packet::Data([1, 2]));
+    let failed: Packet<u8, &str, 2> = Packet::Error("e");
+    assert_eq!(failed.as_ref(), Packet::Error(&"e"));
+}
+
+#[test]
+fn test_bool_const_param() {
+    assert!(Bit::<3>::Set.to_bool());
+    assert_eq!(Bit::<3>::from_bool(false), Bit::Clear);
+    assert_eq!(<Bit<1> as BoolLike>::TRUE, Bit::Set);
+}
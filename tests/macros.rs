@@ -0,0 +1,135 @@
+#![no_std]
+
+extern crate std;
+
+use core::num::ParseIntError;
+use result_like::{BoolLike, OptionLike, ResultLike, ensure_true, try_ok, try_some};
+use std::{borrow::ToOwned, string::String};
+
+#[derive(ResultLike, Debug, PartialEq)]
+enum Outcome<T, E> {
+    Done(T),
+    Failed(E),
+}
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    Parse,
+    Empty,
+    Message(String),
+}
+
+impl From<ParseIntError> for Error {
+    fn from(_: ParseIntError) -> Self {
+        Error::Parse
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::Message(message.to_owned())
+    }
+}
+
+#[test]
+fn test_try_ok() {
+    fn parse(s: &str) -> Outcome<u8, Error> {
+        let n: u8 = try_ok!(s.parse::<u8>());
+        Outcome::Done(n)
+    }
+
+    fn sum(a: &str, b: &str) -> Result<u16, Error> {
+        Ok(u16::from(try_ok!(parse(a))) + u16::from(try_ok!(parse(b))))
+    }
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Checked {
+        Valid(u8),
+        Invalid(Error),
+    }
+
+    fn checked(s: &str) -> Checked {
+        Checked::Valid(try_ok!(parse(s)))
+    }
+
+    assert_eq!(parse("3"), Outcome::Done(3));
+    assert_eq!(parse("x"), Outcome::Failed(Error::Parse));
+    assert_eq!(sum("1", "2"), Ok(3));
+    assert_eq!(sum("1", "-"), Err(Error::Parse));
+    assert_eq!(checked("9"), Checked::Valid(9));
+    assert_eq!(checked(""), Checked::Invalid(Error::Parse));
+}
+
+#[test]
+fn test_try_some() {
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Lookup<T> {
+        Found(T),
+        Missing,
+    }
+
+    fn second(items: &[u8]) -> Lookup<u8> {
+        let rest = try_some!(items.split_first()).1;
+        Lookup::Found(*try_some!(rest.first()))
+    }
+
+    fn doubled(items: &[u8]) -> Option<u16> {
+        Some(u16::from(try_some!(second(items))) * 2)
+    }
+
+    assert_eq!(second(&[1, 2]), Lookup::Found(2));
+    assert_eq!(second(&[1]), Lookup::Missing);
+    assert_eq!(doubled(&[1, 4]), Some(8));
+    assert_eq!(doubled(&[]), None);
+}
+
+#[test]
+fn test_multi_field_payloads() {
+    #[derive(OptionLike)]
+    enum Hit {
+        Found(&'static str, u32),
+        Miss,
+    }
+
+    #[derive(ResultLike)]
+    enum Parsed {
+        Value(u8),
+        Invalid(usize, &'static str),
+    }
+
+    fn find(hit: Hit) -> Option<u32> {
+        let (_, v) = try_some!(hit.into_option());
+        Some(v)
+    }
+
+    fn parse(parsed: Parsed) -> Outcome<u8, (usize, &'static str)> {
+        Outcome::Done(try_ok!(parsed.into_result()))
+    }
+
+    assert_eq!(find(Hit::Found("k", 1)), Some(1));
+    assert_eq!(find(Hit::Miss), None);
+    assert_eq!(parse(Parsed::Value(2)), Outcome::Done(2));
+    assert_eq!(parse(Parsed::Invalid(0, "x")), Outcome::Failed((0, "x")));
+}
+
+#[test]
+fn test_ensure_true() {
+    #[derive(BoolLike, Clone, Copy)]
+    enum Ready {
+        Yes,
+        No,
+    }
+
+    fn start(ready: Ready, input: &str) -> Outcome<usize, Error> {
+        ensure_true!(ready, "not ready");
+        ensure_true!(!input.is_empty(), Error::Empty);
+        Outcome::Done(input.len())
+    }
+
+    assert_eq!(start(Ready::Yes, "go"), Outcome::Done(2));
+    assert_eq!(start(Ready::Yes, ""), Outcome::Failed(Error::Empty));
+    assert_eq!(
+        start(Ready::No, "go"),
+        Outcome::Failed(Error::Message("not ready".to_owned()))
+    );
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "nightly")]
+#![feature(try_trait_v2)]
+
+use result_like::{OptionLike, ResultLike};
+
+#[derive(ResultLike, Debug, PartialEq)]
+#[result_like(try)]
+enum Outcome<T, E> {
+    Done(T),
+    Failed(E),
+}
+
+#[derive(OptionLike, Debug, PartialEq)]
+#[option_like(try)]
+enum Lookup<T> {
+    Found(T),
+    Missing,
+}
+
+#[derive(Debug, PartialEq)]
+struct Error(String);
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error(message.to_owned())
+    }
+}
+
+fn parse(s: &str) -> Outcome<u8, &str> {
+    match s.parse() {
+        Ok(n) => Outcome::Done(n),
+        Err(_) => Outcome::Failed("not a number"),
+    }
+}
+
+#[test]
+fn test_result_try() {
+    fn sum(a: &str, b: &str) -> Outcome<u16, Error> {
+        Outcome::Done(u16::from(parse(a)?) + u16::from(parse(b)?))
+    }
+
+    fn std_sum(a: &str, b: &str) -> Result<u16, Error> {
+        Ok(u16::from(parse(a)?) + u16::from(parse(b)?))
+    }
+
+    fn from_std(s: &str) -> Outcome<u8, String> {
+        let n: u8 = s.parse().map_err(|_| "bad".to_owned())?;
+        Outcome::Done(n + 1)
+    }
+
+    assert_eq!(sum("1", "2"), Outcome::Done(3));
+    assert_eq!(sum("1", "x"), Outcome::Failed(Error::from("not a number")));
+    assert_eq!(std_sum("4", "5"), Ok(9));
+    assert_eq!(std_sum("-", "5"), Err(Error::from("not a number")));
+    assert_eq!(from_std("1"), Outcome::Done(2));
+    assert_eq!(from_std(""), Outcome::Failed("bad".to_owned()));
+}
+
+#[test]
+fn test_option_try() {
+    fn second(items: &[u8]) -> Lookup<u8> {
+        let rest = items.split_first()?.1;
+        Lookup::Found(*rest.first()?)
+    }
+
+    fn doubled(items: &[u8]) -> Option<u16> {
+        Some(u16::from(second(items)?) * 2)
+    }
+
+    assert_eq!(second(&[1, 2]), Lookup::Found(2));
+    assert_eq!(second(&[1]), Lookup::Missing);
+    assert_eq!(doubled(&[1, 4]), Some(8));
+    assert_eq!(doubled(&[]), None);
+}
+
+#[test]
+fn test_tuple_payload_try() {
+    #[derive(ResultLike, Debug, PartialEq)]
+    #[result_like(try)]
+    enum Span {
+        At(usize, usize),
+        Invalid(&'static str, usize),
+    }
+
+    fn width(span: Span) -> Result<usize, (&'static str, usize)> {
+        let (start, end) = span?;
+        Ok(end - start)
+    }
+
+    assert_eq!(width(Span::At(2, 5)), Ok(3));
+    assert_eq!(width(Span::Invalid("x", 1)), Err(("x", 1)));
+}
+
+#[test]
+fn test_without_try() {
+    use std::{convert::Infallible, ops::FromResidual};
+
+    // Without the opt-in the derive leaves `FromResidual` free to implement.
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Slot<T> {
+        Filled(T),
+        Empty,
+    }
+
+    impl<T> FromResidual<Option<Infallible>> for Slot<T> {
+        fn from_residual(_: Option<Infallible>) -> Self {
+            Slot::Empty
+        }
+    }
+
+    assert_eq!(Slot::<u8>::from_residual(None), Slot::Empty);
+    assert_eq!(Slot::Filled(1).into_option(), Some(1));
+}
@@ -46,7 +46,8 @@ fn test_xo() {
 
 #[test]
 fn test_yo() {
-    #[derive(OptionLike, is_macro::Is)]
+    #[derive(OptionLike)]
+    #[option_like(aliases)]
     enum YOption<T> {
         Tone(T),
         Mome,
@@ -61,3 +62,636 @@ fn test_yo() {
 
     assert!(op == Some("s"));
 }
+
+#[test]
+fn test_trait() {
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Lookup<T> {
+        Found(T),
+        Missing,
+    }
+
+    fn first_present<O: OptionLike>(mut items: impl Iterator<Item = O>) -> Option<O::SomeType> {
+        items.find_map(O::into_option)
+    }
+
+    let items = vec![Lookup::Missing, Lookup::Found(1), Lookup::Found(2)];
+    assert_eq!(first_present(items.into_iter()), Some(1));
+
+    fn bump<O: OptionLike<SomeType = i32>>(mut o: O) -> O {
+        if let Some(v) = o.as_option_mut() {
+            *v += 1;
+        }
+        o
+    }
+    assert_eq!(bump(Lookup::Found(1)), Lookup::Found(2));
+    assert_eq!(bump(Lookup::Missing), Lookup::Missing);
+
+    let mut l = Lookup::Missing;
+    assert_eq!(*OptionLike::get_or_insert(&mut l, 5), 5);
+    assert_eq!(OptionLike::take(&mut l), Lookup::Found(5));
+    assert_eq!(l, Lookup::Missing);
+    assert_eq!(OptionLike::xor(l, Lookup::Found(3)), Lookup::Found(3));
+}
+
+#[test]
+fn test_std_option() {
+    #[derive(OptionLike)]
+    enum Cached<T> {
+        Hit(T),
+        Miss,
+    }
+
+    fn total<O: OptionLike<SomeType = u32>>(items: impl IntoIterator<Item = O>) -> u32 {
+        items.into_iter().map(|o| o.unwrap_or(0)).sum()
+    }
+
+    assert_eq!(total([Some(1), None, Some(2)]), 3);
+    assert_eq!(total([Cached::Hit(1), Cached::Miss, Cached::Hit(2)]), 3);
+
+    let mut o = Some(1);
+    assert_eq!(OptionLike::as_option_mut(&mut o).map(|v| *v), Some(1));
+    assert_eq!(<Option<u32> as OptionLike>::from_value(2), Some(2));
+}
+
+#[test]
+fn test_rebind() {
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Lookup<T> {
+        Found(T),
+        Missing,
+    }
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Number {
+        Value(i64),
+        Nan,
+    }
+
+    fn lengths<O: OptionLike<SomeType = &'static str>>(o: O) -> O::Rebind<usize> {
+        OptionLike::map(o, str::len)
+    }
+
+    let found: Lookup<usize> = lengths(Lookup::Found("abc"));
+    assert_eq!(found, Lookup::Found(3));
+    assert_eq!(lengths(Some("ab")), Some(2));
+
+    let zipped: Lookup<(i32, char)> = OptionLike::zip(Lookup::Found(1), Lookup::Found('a'));
+    assert_eq!(zipped, Lookup::Found((1, 'a')));
+    let chained = OptionLike::and_then(Lookup::Found(2), |v| Lookup::Found(v * 2));
+    assert_eq!(chained, Lookup::Found(4));
+
+    let halved: Option<f64> = OptionLike::map(Number::Value(3), |v| v as f64 / 2.0);
+    assert_eq!(halved, Some(1.5));
+    assert_eq!(OptionLike::and(Number::Nan, Some(1)), None);
+}
+
+#[test]
+fn test_convert() {
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Cached<T> {
+        Hit(T),
+        Miss,
+    }
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Lookup<T> {
+        Found(T),
+        Missing,
+    }
+
+    assert_eq!(Cached::Hit(1).convert::<Lookup<_>>(), Lookup::Found(1));
+    assert_eq!(Cached::<i32>::Miss.convert::<Lookup<_>>(), Lookup::Missing);
+    assert_eq!(Lookup::convert_from(Some("x")), Lookup::Found("x"));
+    assert_eq!(Lookup::Found(2).convert::<Option<_>>(), Some(2));
+}
+
+#[test]
+fn test_lifetime() {
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Found<'a, T> {
+        Hit(&'a T),
+        Miss,
+    }
+
+    let value = String::from("v");
+    let found = Found::Hit(&value);
+    assert_eq!(found.into_option(), Some(&value));
+    assert_eq!(Found::Hit(&value).cloned(), Some(value.clone()));
+    assert_eq!(Found::Hit(&3).copied(), Some(3));
+    assert_eq!(Found::<u8>::Miss.copied(), None);
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Word<'src> {
+        Some(&'src str),
+        None,
+    }
+
+    let text = String::from("word");
+    let word = Word::Some(&text[..2]);
+    assert_eq!(word.unwrap_or("none"), "wo");
+    let len: Option<usize> = OptionLike::map(Word::Some(&text), str::len);
+    assert_eq!(len, Some(4));
+}
+
+#[test]
+fn test_named_field() {
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Lookup<T> {
+        Found { value: T },
+        Missing,
+    }
+
+    let found = Lookup::from_option(Some(1));
+    assert_eq!(found, Lookup::Found { value: 1 });
+    assert_eq!(found.as_option(), Some(&1));
+    assert_eq!(found.map(|v| v + 1), Lookup::Found { value: 2 });
+    assert_eq!(found.as_ref().cloned(), found);
+    assert_eq!(Lookup::<u8>::Missing.and(found), Lookup::Missing);
+
+    let mut slot = Lookup::Missing;
+    *slot.get_or_insert_with(|| 3) += 1;
+    assert_eq!(slot.replace(5), Lookup::Found { value: 4 });
+    assert_eq!(slot.unwrap(), 5);
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Setting {
+        Set { level: u8 },
+        Unset,
+    }
+    assert_eq!(Setting::from(2).into_option(), Some(2));
+    assert_eq!(Setting::Unset.unwrap_or(0), 0);
+}
+
+#[test]
+fn test_tuple_payload() {
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Hit {
+        Found(&'static str, u32),
+        Miss,
+    }
+
+    let hit = Hit::from_option(Some(("key", 1)));
+    assert_eq!(hit, Hit::Found("key", 1));
+    assert_eq!(hit.as_option(), Some((&"key", &1)));
+    assert_eq!(Hit::Miss.into_option(), None);
+    assert_eq!(
+        hit.map(|(k, v)| format!("{k}={v}")),
+        Some("key=1".to_owned())
+    );
+
+    let mut slot = Hit::Miss;
+    *slot.get_or_insert(("new", 2)).1 += 1;
+    assert_eq!(slot.into_option(), Some(("new", 3)));
+    assert_eq!(
+        Hit::from(("a", 0)).into_iter().collect::<Vec<_>>(),
+        [("a", 0)]
+    );
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Entry<K, V> {
+        Occupied(K, V),
+        Vacant,
+    }
+    let entry: Entry<char, u8> = Some(('x', 9)).into();
+    assert_eq!(Option::from(entry), Some(('x', 9)));
+}
+
+#[test]
+fn test_role_attribute() {
+    #[derive(OptionLike, Debug, PartialEq, PartialOrd)]
+    enum Lookup<T> {
+        Missing,
+        #[option_like(some)]
+        Found(T),
+    }
+
+    assert_eq!(Lookup::from_option(Some(1)), Lookup::Found(1));
+    assert_eq!(Lookup::<u8>::default(), Lookup::Missing);
+    assert_eq!(Lookup::Found(2).map(|v| v * 2).into_option(), Some(4));
+    assert!(Lookup::Missing < Lookup::Found(0));
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Cache {
+        #[option_like(none)]
+        Cold,
+        Warm(u32),
+    }
+    assert_eq!(Cache::Warm(3).unwrap(), 3);
+    assert_eq!(Cache::from_option(None), Cache::Cold);
+}
+
+#[test]
+fn test_skip() {
+    #[derive(OptionLike, Clone, Copy, Default, Debug, PartialEq)]
+    #[option_like(skip(clone, copy, default, into_iter, map))]
+    enum Slot<T> {
+        Filled(T),
+        #[default]
+        Empty,
+    }
+
+    impl<T> Slot<T> {
+        fn map(self, f: impl FnOnce(T) -> T) -> Self {
+            match self {
+                Slot::Filled(v) => Slot::Filled(f(v)),
+                Slot::Empty => Slot::Empty,
+            }
+        }
+    }
+
+    let slot = Slot::Filled(1);
+    assert_eq!(slot.map(|v| v + 1), Slot::Filled(2));
+    let copy = slot;
+    assert_eq!(copy.unwrap(), 1);
+    assert_eq!(Slot::<u8>::default(), Slot::Empty);
+    assert_eq!(slot.as_ref().copied(), slot);
+    assert_eq!(OptionLike::map(slot, |v| v * 3).into_option(), Some(3));
+}
+
+// Without `OptionLike` in scope, so that generated bodies can't fall back to
+// the trait for filtered out methods.
+mod filtered {
+    #[derive(result_like::OptionLike, Debug, PartialEq)]
+    #[option_like(skip(take))]
+    pub enum Skipped {
+        Filled(u8),
+        Empty,
+    }
+
+    #[derive(result_like::OptionLike, Debug, PartialEq)]
+    #[option_like(only(take_if))]
+    pub enum Only {
+        Filled(u8),
+        Empty,
+    }
+}
+
+#[test]
+fn test_filtered_take_if() {
+    use filtered::{Only, Skipped};
+
+    let mut skipped = Skipped::Filled(1);
+    assert_eq!(skipped.take_if(|v| *v > 1), Skipped::Empty);
+    assert_eq!(skipped.take_if(|v| *v == 1), Skipped::Filled(1));
+    assert_eq!(skipped, Skipped::Empty);
+
+    let mut only = Only::Filled(2);
+    assert_eq!(only.take_if(|v| *v == 2), Only::Filled(2));
+    assert_eq!(only, Only::Empty);
+}
+
+#[deny(unreachable_pub)]
+mod cache {
+    use result_like::OptionLike;
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    pub(crate) enum Cache<T> {
+        Hit(T),
+        Miss,
+    }
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    #[option_like(vis = "pub(super)")]
+    pub(crate) enum Narrow {
+        Hit(u8),
+        Miss,
+    }
+}
+
+#[test]
+fn test_visibility() {
+    use cache::{Cache, Narrow};
+
+    assert_eq!(Cache::Hit(1).map(|v| v + 1).unwrap(), 2);
+    assert_eq!(Cache::<u8>::Miss.into_option(), None);
+    assert_eq!(Narrow::from_option(Some(3)), Narrow::Hit(3));
+}
+
+#[test]
+fn test_aliases() {
+    #[derive(OptionLike, Debug, PartialEq)]
+    #[option_like(aliases)]
+    enum Lookup<T> {
+        Found(T),
+        NotFound,
+    }
+
+    let found = Lookup::Found(2);
+    assert!(found.is_found());
+    assert!(Lookup::<u8>::NotFound.is_not_found());
+    assert_eq!(found.map_found(|v| v * 2), Lookup::Found(4));
+    assert_eq!(Lookup::NotFound.found_or(7), 7);
+    assert_eq!(found.expect_found("found"), 2);
+    assert_eq!(found.unwrap_found(), 2);
+
+    #[derive(OptionLike)]
+    #[option_like(aliases)]
+    enum Reading {
+        Value(f32),
+        Offline,
+    }
+    assert_eq!(Reading::Value(0.5).map_value(|v| v * 2.0), Some(1.0));
+    assert!(Reading::Offline.is_offline());
+}
+
+#[test]
+fn test_predicates() {
+    #[derive(OptionLike, Debug)]
+    enum Cached<T> {
+        Hit(T),
+        Miss,
+    }
+
+    const HIT: bool = Cached::Hit(1).is_some();
+    const MISS: bool = Cached::<u8>::Miss.is_none();
+    const { assert!(HIT && MISS) };
+    assert!(Cached::Hit(3).is_some_and(|v| v > 2));
+    assert!(!Cached::Miss.is_some_and(|v: u8| v > 2));
+    assert!(Cached::Miss.is_none_or(|v: u8| v > 2));
+    assert!(!Cached::Hit(1).is_none_or(|v| v > 2));
+
+    #[derive(OptionLike)]
+    enum Maybe<T> {
+        Some(T),
+        None,
+    }
+    assert!(Maybe::Some(1).is_some() && Maybe::<u8>::None.is_none());
+}
+
+#[test]
+fn test_const() {
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Cell {
+        Value(u8),
+        Empty,
+    }
+
+    const fn lookup(i: usize) -> Cell {
+        if i < 3 {
+            Cell::Value(i as u8 * 2)
+        } else {
+            Cell::Empty
+        }
+    }
+
+    const DOUBLED: u8 = lookup(2).unwrap();
+    const FALLBACK: u8 = lookup(5).unwrap_or(1);
+    assert_eq!((DOUBLED, FALLBACK), (4, 1));
+    const PICKED: Option<u8> = lookup(9).or(lookup(1)).into_option();
+    assert_eq!(PICKED, Some(2));
+    const XORED: Cell = Cell::Empty.xor(Cell::Value(3));
+    assert_eq!(XORED, Cell::Value(3));
+    const FIRST: u8 = lookup(0).expect("in range");
+    assert_eq!(FIRST, 0);
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Slot<T> {
+        Full(T),
+        Vacant,
+    }
+
+    const SLOTS: (Slot<u8>, Slot<u8>) = {
+        let mut slot = Slot::Full(7);
+        if let Some(v) = slot.as_option_mut() {
+            *v += 1;
+        }
+        let taken = slot.take();
+        (slot, taken)
+    };
+    assert_eq!(SLOTS, (Slot::Vacant, Slot::Full(8)));
+    const COPIED: Slot<u8> = Slot::Full(&2).copied();
+    assert_eq!(COPIED, Slot::Full(2));
+    const _: () = assert!(Slot::Full(1).as_ref().is_some());
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point(i8, i8);
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Position
+    where
+        Point: Copy,
+    {
+        At(Point),
+        Nowhere,
+    }
+    const ORIGIN: Point = Position::Nowhere.unwrap_or(Point(0, 0));
+    assert_eq!(ORIGIN, Point(0, 0));
+
+    #[derive(OptionLike)]
+    enum Reading<T: Copy> {
+        Value(T),
+        Offline,
+    }
+    const HALF: f32 = Reading::Value(0.5).unwrap();
+    assert_eq!(HALF, 0.5);
+    const OFFLINE: bool = Reading::<f32>::Offline.as_option().is_none();
+    const { assert!(OFFLINE) };
+}
+
+#[test]
+fn test_std_parity() {
+    use std::pin::Pin;
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Maybe<T> {
+        Just(T),
+        Nothing,
+    }
+
+    for o in [Some(2), None] {
+        let m = || Maybe::from_option(o);
+        assert_eq!(m().is_some_and(|v| v > 1), o.is_some_and(|v| v > 1));
+        assert_eq!(m().is_none_or(|v| v > 2), o.is_none_or(|v| v > 2));
+        assert_eq!(
+            m().map_or_else(|| 0, |v| v * 3),
+            o.map_or_else(|| 0, |v| v * 3)
+        );
+        assert_eq!(m().unwrap_or_default(), o.unwrap_or_default());
+        if o.is_some() {
+            assert_eq!(unsafe { m().unwrap_unchecked() }, unsafe {
+                o.unwrap_unchecked()
+            });
+        }
+
+        let (mut seen, mut std_seen) = (None, None);
+        assert_eq!(
+            m().inspect(|v| seen = Some(*v)).into_option(),
+            o.inspect(|v| std_seen = Some(*v))
+        );
+        assert_eq!(seen, std_seen);
+
+        let (mut a, mut b) = (m(), o);
+        assert!(a.iter().eq(b.iter()));
+        assert!(a.iter_mut().eq(b.iter_mut()));
+        assert!((&a).into_iter().eq(&b));
+        assert!((&mut a).into_iter().eq(&mut b));
+        assert_eq!(a.as_slice(), b.as_slice());
+        assert_eq!(a.as_mut_slice(), b.as_mut_slice());
+        assert_eq!(Maybe::<&i32>::from(&a).into_option(), Option::from(&b));
+        assert_eq!(
+            Maybe::<&mut i32>::from(&mut a).into_option(),
+            Option::from(&mut b)
+        );
+
+        let pinned = Pin::new(&a).as_pin_ref().into_option().map(|v| *v);
+        assert_eq!(pinned, Pin::new(&b).as_pin_ref().map(|v| *v));
+        if let Some(mut v) = Pin::new(&mut a).as_pin_mut().into_option() {
+            *v += 1;
+        }
+        if let Some(mut v) = Pin::new(&mut b).as_pin_mut() {
+            *v += 1;
+        }
+        assert_eq!(a.into_option(), b);
+
+        let (mut a, mut b) = (m(), o);
+        assert_eq!(*a.insert(5), *b.insert(5));
+        assert_eq!(a.into_option(), b);
+
+        let (mut a, mut b) = (m(), o);
+        assert_eq!(*a.get_or_insert_default(), *b.get_or_insert_default());
+        assert_eq!(a.into_option(), b);
+
+        let (mut a, mut b) = (m(), o);
+        let bump = |v: &mut i32| {
+            *v += 1;
+            *v > 5
+        };
+        assert_eq!(a.take_if(bump).into_option(), b.take_if(bump));
+        assert_eq!(a.into_option(), b);
+        assert_eq!(a.take_if(|v| *v > 2).into_option(), b.take_if(|v| *v > 2));
+        assert_eq!(a.into_option(), b);
+
+        for other in [Some('x'), None] {
+            let n = Maybe::from_option(other);
+            assert_eq!(m().zip(n).into_option(), o.zip(other));
+            let n = Maybe::from_option(other);
+            assert_eq!(
+                m().zip_with(n, |v, c| format!("{v}{c}")).into_option(),
+                o.zip(other).map(|(v, c)| format!("{v}{c}"))
+            );
+            let (v, c) = Maybe::from_option(o.zip(other)).unzip();
+            assert_eq!((v.into_option(), c.into_option()), o.zip(other).unzip());
+        }
+
+        assert_eq!(Maybe::Just(m()).flatten().into_option(), Some(o).flatten());
+    }
+    assert_eq!(
+        Maybe::<Maybe<u8>>::Nothing.flatten().into_option(),
+        None::<Option<u8>>.flatten()
+    );
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Cell {
+        Value(u8),
+        Empty,
+    }
+
+    let mut a = Cell::Value(3);
+    let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        a.take_if(|_| panic!("predicate"));
+    }));
+    assert!(panicked.is_err());
+    assert_eq!(a, Cell::Value(3));
+
+    for o in [Some(3), None] {
+        let (mut a, mut b) = (Cell::from_option(o), o);
+        assert!(a.iter().eq(b.iter()));
+        assert_eq!(a.as_slice(), b.as_slice());
+        assert_eq!(*a.get_or_insert_default(), *b.get_or_insert_default());
+        assert_eq!(a.take().into_option(), b.take());
+        assert_eq!(
+            Cell::from_option(o).map_or_else(|| 0, u16::from),
+            o.map_or_else(|| 0, u16::from)
+        );
+    }
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Hit {
+        Found(&'static str, u32),
+        Miss,
+    }
+
+    for o in [Some(("a", 1)), None] {
+        let (mut a, mut b) = (Hit::from_option(o), o);
+        assert!(a.iter().eq(b.as_ref().map(|(k, v)| (k, v))));
+        assert_eq!(
+            a.take_if(|(_, v)| *v > 0).into_option(),
+            b.take_if(|(_, v)| *v > 0)
+        );
+        let (k, v) = a.insert(("b", 2));
+        assert_eq!((*k, *v), *b.insert(("b", 2)));
+        assert_eq!(
+            Hit::from_option(o).inspect(|_| ()).into_option(),
+            o.inspect(|_| ())
+        );
+    }
+}
+
+#[test]
+fn test_non_generic_combinators() {
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Number {
+        Value(i64),
+        Nan,
+    }
+
+    assert_eq!(Number::Value(2).update(|v| v * 3), Number::Value(6));
+    assert_eq!(Number::Nan.update(|v| v * 3), Number::Nan);
+    assert_eq!(Number::Value(2).map(|v| v as f64 / 4.0), Some(0.5));
+    assert_eq!(Number::Value(2).and(Some('a')), Some('a'));
+    assert_eq!(Number::Nan.and(Some('a')), None);
+    assert_eq!(
+        Number::Value(9).and_then(|v| u8::try_from(v).ok()),
+        Some(9u8)
+    );
+    assert_eq!(Number::Value(1).zip(Some("one")), Some((1, "one")));
+    let checked: Number = Number::Value(i64::MAX)
+        .and_then(|v| v.checked_add(1))
+        .into();
+    assert_eq!(checked, Number::Nan);
+
+    let mut n = Number::Value(4);
+    if let Some(v) = n.as_mut() {
+        *v += 1;
+    }
+    assert_eq!(n.as_ref(), Some(&5));
+    assert_eq!(n.copied(), Some(5));
+    assert_eq!(n.cloned(), Some(5));
+    assert_eq!(Number::Nan.as_ref(), None);
+    assert_eq!(Number::Nan.copied(), None);
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Name {
+        Some(String),
+        None,
+    }
+    let mut name = Name::Some("Ferris".to_owned());
+    assert_eq!(name.as_deref(), Some("Ferris"));
+    if let Some(s) = name.as_deref_mut() {
+        s.make_ascii_uppercase();
+    }
+    assert_eq!(name.as_deref(), Some("FERRIS"));
+    assert_eq!(Name::None.as_deref(), None);
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Hit {
+        Found(&'static str, u32),
+        Miss,
+    }
+    assert_eq!(
+        Hit::Found("a", 1).update(|(k, v)| (k, v + 1)),
+        Hit::Found("a", 2)
+    );
+    assert_eq!(
+        Hit::Found("a", 1).and_then(|(_, v)| v.checked_sub(1)),
+        Some(0)
+    );
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    enum Lookup<T> {
+        Found(T),
+        Missing,
+    }
+    assert_eq!(Lookup::Found(1).update(|v| v + 1), Lookup::Found(2));
+}
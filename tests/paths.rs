@@ -0,0 +1,84 @@
+#![allow(dead_code)]
+
+mod shadowed {
+    use result_like::{BoolLike, OptionLike, ResultLike};
+
+    pub type Result<T> = core::result::Result<T, String>;
+    pub type Option = ();
+    pub struct Some;
+    pub struct None;
+    pub struct Ok;
+    pub struct Err;
+    pub trait From {}
+    pub trait Default {}
+    pub trait Clone {}
+
+    #[derive(OptionLike, Debug, PartialEq)]
+    pub enum Cached<T> {
+        Hit(T),
+        Miss,
+    }
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    pub enum Outcome<T, E> {
+        Done(T),
+        Failed(E),
+    }
+
+    #[derive(BoolLike, Clone, Copy, Debug, PartialEq)]
+    pub enum Switch {
+        On,
+        Off,
+    }
+
+    pub fn parse(s: &str) -> Result<Outcome<u8, ()>> {
+        s.parse().map(Outcome::Done).map_err(|_| s.to_owned())
+    }
+}
+
+mod facade {
+    pub use result_like::*;
+}
+
+mod reexported {
+    #[derive(crate::facade::OptionLike)]
+    #[option_like(crate = "crate::facade")]
+    pub enum Cached<T> {
+        Hit(T),
+        Miss,
+    }
+
+    #[derive(crate::facade::ResultLike)]
+    #[result_like(crate = "crate::facade")]
+    pub enum Outcome<T, E> {
+        Done(T),
+        Failed(E),
+    }
+
+    #[derive(crate::facade::BoolLike)]
+    #[bool_like(crate = "crate::facade")]
+    pub enum Switch {
+        On,
+        Off,
+    }
+}
+
+#[test]
+fn test_shadowed_prelude() {
+    use shadowed::{Cached, Outcome, Switch};
+
+    assert_eq!(Cached::from_option(Some(1)).map(|v| v + 1).unwrap(), 2);
+    assert_eq!(Outcome::<u8, ()>::Done(1).into_result(), Ok(1));
+    assert_eq!(shadowed::parse("3"), Ok(Outcome::Done(3)));
+    assert_eq!(Switch::from(true), Switch::On);
+}
+
+#[test]
+fn test_crate_path() {
+    use crate::facade::{BoolLike, OptionLike, ResultLike};
+    use reexported::{Cached, Outcome, Switch};
+
+    assert_eq!(OptionLike::into_option(Cached::Hit(1)), Some(1));
+    assert_eq!(ResultLike::into_result(Outcome::<u8, ()>::Done(2)), Ok(2));
+    assert!(BoolLike::to_bool(&Switch::On));
+}
@@ -75,3 +75,615 @@ fn test_result() {
     xo = xo.and_then(|_| YResult::Errr(()));
     assert_eq!(xo.into_result(), Err(()));
 }
+
+#[test]
+fn test_trait() {
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Outcome<T, E> {
+        Done(T),
+        Failed(E),
+    }
+
+    fn describe<R: ResultLike<ErrType = &'static str>>(r: R) -> &'static str {
+        if r.is_ok() { "ok" } else { r.err().unwrap() }
+    }
+
+    assert_eq!(describe(Outcome::<i32, _>::Done(1)), "ok");
+    assert_eq!(describe(Outcome::<i32, _>::Failed("bad")), "bad");
+
+    fn double<R: ResultLike<OkType = i32>>(mut r: R) -> R {
+        if let Ok(v) = r.as_result_mut() {
+            *v *= 2;
+        }
+        r
+    }
+    assert_eq!(double(Outcome::<_, ()>::Done(2)), Outcome::Done(4));
+    assert_eq!(double(Outcome::Failed(())), Outcome::Failed(()));
+
+    let failed: Outcome<i32, i32> = ResultLike::from_err(3);
+    assert_eq!(ResultLike::ok(failed), None);
+    assert!(ResultLike::is_err_and(Outcome::<(), _>::Failed(3), |e| e == 3));
+    assert_eq!(
+        ResultLike::map_or_else(Outcome::<i32, i32>::Failed(3), |e| -e, |v| v),
+        -3
+    );
+}
+
+#[test]
+fn test_std_result() {
+    #[derive(ResultLike)]
+    enum Trial<T, E> {
+        Success(T),
+        Failure(E),
+    }
+
+    fn errors<R: ResultLike<ErrType = &'static str>>(items: impl IntoIterator<Item = R>) -> usize {
+        items.into_iter().filter(|r| r.is_err()).count()
+    }
+
+    assert_eq!(errors([Ok(1), Err("a"), Err("b")]), 2);
+    assert_eq!(errors([Trial::Success(1), Trial::Failure("a")]), 1);
+    assert_eq!(<Result<u8, ()> as ResultLike>::from_ok(1), Ok(1));
+}
+
+#[test]
+fn test_rebind() {
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Outcome<T, E> {
+        Done(T),
+        Failed(E),
+    }
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Trial {
+        Success(String),
+        Failure(String),
+    }
+
+    fn stringify<R>(r: R) -> R::RebindErr<String>
+    where
+        R: ResultLike,
+        R::ErrType: ToString,
+    {
+        ResultLike::map_err(r, |e| e.to_string())
+    }
+
+    let failed: Outcome<(), String> = stringify(Outcome::Failed(3));
+    assert_eq!(failed, Outcome::Failed("3".to_owned()));
+    assert_eq!(stringify(Err::<(), _>(4)), Err("4".to_owned()));
+
+    let doubled = ResultLike::and_then(Outcome::<_, ()>::Done(2), |v| Outcome::Done(v * 2));
+    assert_eq!(doubled, Outcome::Done(4));
+    let recovered =
+        ResultLike::or_else(Outcome::<i32, _>::Failed(1), |_| Outcome::<_, ()>::Done(0));
+    assert_eq!(recovered, Outcome::Done(0));
+
+    let len: Result<usize, String> = ResultLike::map(Trial::Success("ab".to_owned()), |s| s.len());
+    assert_eq!(len, Ok(2));
+}
+
+#[test]
+fn test_convert() {
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Outcome<T, E> {
+        Done(T),
+        Failed(E),
+    }
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Trial {
+        Success(String),
+        Failure(String),
+    }
+
+    let trial = Trial::Failure("no".to_owned());
+    assert_eq!(
+        trial.convert::<Outcome<_, _>>(),
+        Outcome::Failed("no".to_owned())
+    );
+    assert_eq!(
+        Trial::convert_from(Ok("yes".to_owned())),
+        Trial::Success("yes".to_owned())
+    );
+    assert_eq!(Outcome::<u8, ()>::Done(1).convert::<Result<_, _>>(), Ok(1));
+}
+
+#[test]
+fn test_param_position() {
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Reply<E, T> {
+        Good(T),
+        Bad(E),
+    }
+
+    let good: Reply<(), u8> = Reply::Good(1);
+    let mapped: Reply<(), String> = good.map(|v| v.to_string());
+    assert_eq!(mapped, Reply::Good("1".to_owned()));
+    let bad: Reply<u8, ()> = Reply::Bad(2);
+    assert_eq!(bad.map_err(|e| e * 2), Reply::Bad(4));
+    assert_eq!(Reply::<u8, u8>::Good(3).as_ref(), Reply::Good(&3));
+    assert_eq!(Reply::<u8, u8>::Good(3).as_ref().copied(), Reply::Good(3));
+    assert_eq!(
+        Reply::<u8, u8>::Bad(1).or::<()>(Reply::Good(5)),
+        Reply::Good(5)
+    );
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Parsed<'src, T, const N: usize> {
+        Ok(T),
+        Err(&'src str),
+    }
+
+    let parsed: Parsed<'_, u8, 4> = Parsed::Ok(1);
+    let widened: Parsed<'_, u16, 4> = parsed.map(u16::from);
+    assert_eq!(widened, Parsed::Ok(1));
+    assert_eq!(
+        Parsed::<u8, 4>::Err("eof").map(u16::from),
+        Parsed::Err("eof")
+    );
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Keyed<K, T> {
+        Hit(T),
+        Miss(Vec<K>),
+    }
+
+    let hit: Keyed<char, u8> = Keyed::Hit(1);
+    assert_eq!(hit.and_then(|v| Keyed::Hit(v == 1)), Keyed::Hit(true));
+    assert_eq!(
+        Keyed::<char, u8>::Miss(vec!['k']).and(Keyed::Hit(())),
+        Keyed::Miss(vec!['k'])
+    );
+}
+
+#[test]
+fn test_lifetime() {
+    #[derive(Debug, PartialEq)]
+    struct ParseError;
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Parsed<'src> {
+        Ok(&'src str),
+        Err(ParseError),
+    }
+
+    let source = String::from("let x");
+    let parsed = Parsed::Ok(&source[..3]);
+    assert_eq!(parsed.as_result(), Ok(&"let"));
+    let len: Result<usize, ParseError> = ResultLike::map(parsed, str::len);
+    assert_eq!(len, Ok(3));
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Borrowed<'a, T, E> {
+        Ok(&'a T),
+        Err(E),
+    }
+
+    let value = vec![1];
+    assert_eq!(Borrowed::<_, ()>::Ok(&value).cloned(), Ok(vec![1]));
+    assert_eq!(Borrowed::<_, ()>::Ok(&1).copied(), Ok(1));
+    assert_eq!(Borrowed::<u8, _>::Err('e').copied(), Err('e'));
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Located<'src, T, E> {
+        Ok(T),
+        Err(&'src E),
+    }
+
+    let err = 'x';
+    let located: Located<'_, u8, char> = Located::Err(&err);
+    let mapped: Located<'_, u16, char> = located.map(u16::from);
+    assert_eq!(mapped, Located::Err(&'x'));
+}
+
+#[test]
+fn test_named_field() {
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Outcome<T, E> {
+        Done { output: T },
+        Failed { error: E },
+    }
+
+    let done: Outcome<u8, ()> = Outcome::from_result(Ok(1));
+    assert_eq!(done, Outcome::Done { output: 1 });
+    assert_eq!(done.as_ref().copied(), Outcome::Done { output: 1 });
+    assert_eq!(done.map(u16::from).into_result(), Ok(1u16));
+
+    let failed: Outcome<u8, &str> = Outcome::Failed { error: "bad" };
+    assert_eq!(failed.map_err(str::len), Outcome::Failed { error: 3 });
+    assert_eq!(failed.unwrap_or(0), 0);
+    assert_eq!(failed.as_result(), Err(&"bad"));
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Trial {
+        Success { message: String },
+        Failure { code: i32 },
+    }
+    assert_eq!(
+        Trial::Success {
+            message: "ok".to_owned()
+        }
+        .unwrap(),
+        "ok"
+    );
+    assert_eq!(Trial::Failure { code: 2 }.map_or(0, |m| m.len()), 0);
+}
+
+#[test]
+fn test_tuple_payload() {
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Reply<T> {
+        Ok(T),
+        Err(u16, String),
+    }
+
+    let reply: Reply<u8> = Reply::from_result(Err((404, "missing".to_owned())));
+    assert_eq!(reply, Reply::Err(404, "missing".to_owned()));
+    assert_eq!(reply.as_result(), Err((&404, &"missing".to_owned())));
+    assert_eq!(reply.map_err(|(code, _)| code).into_result(), Err(404));
+    assert_eq!(Reply::Ok(1u8).map(u16::from), Reply::Ok(1u16));
+
+    let mut reply: Reply<u8> = Reply::Err(500, String::new());
+    if let Err((code, _)) = reply.as_result_mut() {
+        *code += 3;
+    }
+    assert_eq!(reply.unwrap_err(), (503, String::new()));
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Span {
+        Found(usize, usize),
+        Invalid(String),
+    }
+    assert_eq!(Span::Found(1, 3).map(|(a, b)| b - a), Ok(2));
+    assert_eq!(Span::from(Ok((0, 1))).unwrap(), (0, 1));
+}
+
+#[test]
+fn test_role_attribute() {
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Outcome<T, E> {
+        #[result_like(err)]
+        Failed(E),
+        #[result_like(ok)]
+        Done(T),
+    }
+
+    let done: Outcome<u8, &str> = Outcome::from_result(Ok(1));
+    assert_eq!(done, Outcome::Done(1));
+    assert_eq!(Outcome::<u8, _>::Failed("no").into_result(), Err("no"));
+    assert_eq!(done.map(u16::from).unwrap(), 1u16);
+}
+
+#[test]
+fn test_only() {
+    #[derive(ResultLike, Debug, PartialEq)]
+    #[result_like(only(into_result, map, unwrap))]
+    enum Outcome<T> {
+        Done(T),
+        Failed(String),
+    }
+
+    impl<T> Outcome<T> {
+        fn is_ok(&self) -> &'static str {
+            "custom"
+        }
+    }
+
+    let done = Outcome::Done(2);
+    assert_eq!(done.is_ok(), "custom");
+    assert_eq!(done.map(|v| v * 2).unwrap(), 4);
+    assert_eq!(
+        Outcome::<u8>::Failed("no".to_owned()).into_result(),
+        Err("no".to_owned())
+    );
+}
+
+#[test]
+fn test_aliases() {
+    #[derive(ResultLike, Debug, PartialEq)]
+    #[result_like(aliases)]
+    enum Trial<T, E> {
+        Success(T),
+        Failure(E),
+    }
+
+    let success: Trial<u8, &str> = Trial::Success(1);
+    let failure: Trial<u8, &str> = Trial::Failure("bad");
+    assert!(success.is_success() && failure.is_failure());
+    assert_eq!(success.map_success(u16::from), Trial::Success(1u16));
+    assert_eq!(failure.map_failure(str::len), Trial::Failure(3));
+    assert_eq!(failure.success_or(0), 0);
+    assert_eq!(success.unwrap_success(), 1);
+    assert_eq!(failure.expect_failure("failure"), "bad");
+    assert_eq!(failure.unwrap_failure(), "bad");
+
+    #[derive(ResultLike)]
+    #[result_like(aliases)]
+    enum Parsed {
+        Number(i64),
+        Garbage(String),
+    }
+    assert_eq!(
+        Parsed::Garbage("x".to_owned()).map_garbage(|s| s.len()),
+        Err(1)
+    );
+    assert_eq!(Parsed::Number(2).expect_number("number"), 2);
+}
+
+#[test]
+fn test_predicates() {
+    #[derive(ResultLike)]
+    enum Outcome<T, E> {
+        Done(T),
+        Failed(E),
+    }
+
+    const _: () = assert!(Outcome::<u8, u8>::Done(1).is_ok());
+    assert!(Outcome::<u8, u8>::Failed(1).is_err());
+    assert!(Outcome::<u8, u8>::Done(2).is_ok_and(|v| v == 2));
+    assert!(!Outcome::<u8, u8>::Done(2).is_err_and(|e| e == 2));
+    assert!(Outcome::<u8, &str>::Failed("bad").is_err_and(|e| e.len() == 3));
+
+    #[derive(ResultLike)]
+    enum Plain {
+        Ok(u8),
+        Err(String),
+    }
+    assert!(Plain::Ok(1).is_ok() && Plain::Err(String::new()).is_err());
+    assert_eq!(Plain::Err("e".to_owned()).map_err(|e| e.len()), Err(1));
+}
+
+#[test]
+fn test_const() {
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Parsed {
+        Digit(u32),
+        Invalid(char),
+    }
+
+    const fn digit(c: char) -> Parsed {
+        match c.to_digit(10) {
+            Some(d) => Parsed::Digit(d),
+            None => Parsed::Invalid(c),
+        }
+    }
+
+    const SEVEN: u32 = digit('7').unwrap_or(0);
+    const INVALID: bool = digit('x').is_err();
+    const { assert!(SEVEN == 7 && INVALID) };
+    const BAD: Result<u32, char> = digit('x').into_result();
+    assert_eq!(BAD, Err('x'));
+    const ONE: Parsed = Parsed::from_result(Ok(1));
+    assert_eq!(ONE, Parsed::Digit(1));
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Outcome<T, E: Copy> {
+        Done(T),
+        Failed(E),
+    }
+
+    const BUMPED: Outcome<u8, ()> = {
+        let mut outcome = Outcome::Done(1);
+        if let Ok(v) = outcome.as_result_mut() {
+            *v += 1;
+        }
+        outcome
+    };
+    assert_eq!(BUMPED, Outcome::Done(2));
+    const THREE: Outcome<u8, ()> = Outcome::Done(&3).copied();
+    assert_eq!(THREE, Outcome::Done(3));
+    const _: () = assert!(Outcome::<u8, u8>::Failed(1).as_result().is_err());
+}
+
+#[test]
+fn test_std_parity() {
+    use std::convert::Infallible;
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Outcome<T, E> {
+        Done(T),
+        Failed(E),
+    }
+
+    let results: [Result<i32, String>; 2] = [Ok(2), Err("bad".to_owned())];
+    for r in results {
+        let o = || Outcome::from_result(r.clone());
+        let r = || r.clone();
+        assert_eq!(o().ok(), r().ok());
+        assert_eq!(o().err(), r().err());
+        assert_eq!(o().is_ok(), r().is_ok());
+        assert_eq!(o().is_err(), r().is_err());
+        assert_eq!(o().is_ok_and(|v| v > 1), r().is_ok_and(|v| v > 1));
+        assert_eq!(
+            o().is_err_and(|e| e.len() > 1),
+            r().is_err_and(|e| e.len() > 1)
+        );
+        assert_eq!(
+            o().map_or_else(|e| e.len() as i32, |v| v * 3),
+            r().map_or_else(|e| e.len() as i32, |v| v * 3)
+        );
+        assert_eq!(o().unwrap_or_default(), r().unwrap_or_default());
+        if r().is_ok() {
+            assert_eq!(unsafe { o().unwrap_unchecked() }, unsafe {
+                r().unwrap_unchecked()
+            });
+        } else {
+            assert_eq!(unsafe { o().unwrap_err_unchecked() }, unsafe {
+                r().unwrap_err_unchecked()
+            });
+            assert_eq!(o().expect_err("err"), r().expect_err("err"));
+        }
+
+        let (mut seen, mut std_seen) = (Vec::new(), Vec::new());
+        let inspected = o()
+            .inspect(|v| seen.push(v.to_string()))
+            .inspect_err(|e| seen.push(e.clone()));
+        let std_inspected = r()
+            .inspect(|v| std_seen.push(v.to_string()))
+            .inspect_err(|e| std_seen.push(e.clone()));
+        assert_eq!(inspected.into_result(), std_inspected);
+        assert_eq!(seen, std_seen);
+
+        let (mut a, mut b) = (o(), r());
+        assert!(a.iter().eq(b.iter()));
+        assert!(a.iter_mut().eq(b.iter_mut()));
+        assert!((&a).into_iter().eq(&b));
+        assert!((&mut a).into_iter().eq(&mut b));
+
+        let (a, b) = (o().map(Box::new), r().map(Box::new));
+        assert_eq!(a.as_deref_ok().into_result(), b.as_deref());
+        assert_eq!(
+            a.as_deref_err().into_result(),
+            b.as_ref().map_err(|e| &e[..])
+        );
+        assert_eq!(a.as_deref().into_result(), b.as_deref().map_err(|e| &e[..]));
+        let (mut a, mut b) = (a, b);
+        if let Ok(v) = a.as_deref_mut_ok().into_result() {
+            *v += 1;
+        }
+        if let Ok(v) = b.as_deref_mut() {
+            *v += 1;
+        }
+        if let Err(e) = a.as_deref_mut_err().into_result() {
+            e.make_ascii_uppercase();
+        }
+        if let Err(e) = b.as_mut() {
+            e.make_ascii_uppercase();
+        }
+        assert_eq!(
+            a.as_deref_mut().into_result().map(|v| *v),
+            b.as_deref_mut().map(|v| *v).map_err(|e| &mut e[..])
+        );
+
+        assert_eq!(
+            Outcome::Done(o()).flatten().into_result(),
+            Ok(r()).and_then(|r| r)
+        );
+        assert_eq!(
+            Outcome::<Outcome<i32, String>, String>::Failed("outer".to_owned())
+                .flatten()
+                .into_result(),
+            Err::<Result<i32, String>, _>("outer".to_owned()).and_then(|r| r)
+        );
+    }
+
+    let ok: Outcome<u8, Infallible> = Outcome::Done(1);
+    assert_eq!(ok.into_ok(), 1);
+    let err: Outcome<Infallible, u8> = Outcome::Failed(2);
+    assert_eq!(err.into_err(), 2);
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Parsed {
+        Number(u32),
+        Invalid(String),
+    }
+
+    let results: [Result<u32, String>; 2] = [Ok(3), Err("x".to_owned())];
+    for r in results {
+        let p = || Parsed::from_result(r.clone());
+        let r = || r.clone();
+        assert_eq!(p().ok(), r().ok());
+        assert_eq!(p().err(), r().err());
+        assert!(p().iter().eq(r().iter()));
+        assert_eq!(
+            p().map_or_else(|e| e.len() as u32, |v| v + 1),
+            r().map_or_else(|e| e.len() as u32, |v| v + 1)
+        );
+        assert_eq!(p().unwrap_or_default(), r().unwrap_or_default());
+        assert_eq!(
+            p().inspect(|_| ()).inspect_err(|_| ()).into_result(),
+            r().inspect(|_| ()).inspect_err(|_| ())
+        );
+    }
+}
+
+#[test]
+fn test_non_generic_combinators() {
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Trial {
+        Success(String),
+        Failure(String),
+    }
+
+    let ok = || Trial::Success("ok".to_owned());
+    let err = || Trial::Failure("err".to_owned());
+    assert_eq!(ok().update(|s| s + "!"), Trial::Success("ok!".to_owned()));
+    assert_eq!(err().update(|s| s + "!"), err());
+    assert_eq!(
+        err().update_err(|s| s.to_uppercase()),
+        Trial::Failure("ERR".to_owned())
+    );
+    assert_eq!(ok().map(|s| s.len()), Ok(2));
+    assert_eq!(err().map_err(|s| s.len()), Err(3));
+    assert_eq!(ok().and(Ok::<_, String>(1)), Ok(1));
+    assert_eq!(err().and_then(|s| Ok(s.len())), Err("err".to_owned()));
+    assert_eq!(err().or(Ok::<_, u8>("x".to_owned())), Ok("x".to_owned()));
+    assert_eq!(
+        err().or_else(|e| if e.is_empty() { Ok(e) } else { Err(e.len()) }),
+        Err(3)
+    );
+    let recovered: Trial = err().or_else(|e| Ok::<_, String>(e + "?")).into();
+    assert_eq!(recovered, Trial::Success("err?".to_owned()));
+
+    let mut t = ok();
+    assert_eq!(t.as_ref(), Ok(&"ok".to_owned()));
+    if let Ok(s) = t.as_mut() {
+        s.push('!');
+    }
+    assert_eq!(t.as_ref().map(String::as_str), Ok("ok!"));
+    assert_eq!(err().as_ref(), Err(&"err".to_owned()));
+    assert_eq!(t.cloned(), Ok("ok!".to_owned()));
+
+    let mut t = ok();
+    assert_eq!(t.as_deref(), Ok("ok"));
+    assert_eq!(err().as_deref(), Err("err"));
+    assert_eq!(t.as_deref_ok(), Ok("ok"));
+    assert_eq!(err().as_deref_ok(), Err(&"err".to_owned()));
+    assert_eq!(err().as_deref_err(), Err("err"));
+    if let Ok(s) = t.as_deref_mut() {
+        s.make_ascii_uppercase();
+    }
+    if let Ok(s) = t.as_deref_mut_ok() {
+        assert_eq!(s, "OK");
+    }
+    let mut e = err();
+    if let Err(s) = e.as_deref_mut_err() {
+        s.make_ascii_uppercase();
+    }
+    assert_eq!(e.as_deref(), Err("ERR"));
+
+    // Only the ok payload can be replaced.
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Outcome<T> {
+        Done(T),
+        Failed(String),
+    }
+    let mut done = Outcome::Done(vec![1, 2]);
+    assert_eq!(done.as_ref(), Ok(&vec![1, 2]));
+    assert_eq!(done.as_deref(), Ok(&[1, 2][..]));
+    assert_eq!(done.as_deref_ok(), Ok(&[1, 2][..]));
+    if let Ok(v) = done.as_deref_mut_ok() {
+        v.reverse();
+    }
+    assert_eq!(done.as_deref_err(), Ok(&vec![2, 1]));
+    let mut failed = Outcome::<Vec<u8>>::Failed("no".to_owned());
+    assert_eq!(failed.as_deref(), Err("no"));
+    if let Err(s) = failed.as_deref_mut_err() {
+        s.make_ascii_uppercase();
+    }
+    assert_eq!(failed.as_deref_ok(), Err(&"NO".to_owned()));
+    assert_eq!(failed.as_mut(), Err(&mut "NO".to_owned()));
+
+    #[derive(ResultLike, Debug, PartialEq)]
+    enum Reading {
+        Value(u16),
+        Fault(u8),
+    }
+    let mut r = Reading::Value(7);
+    if let Ok(v) = r.as_mut() {
+        *v += 1;
+    }
+    assert_eq!(r.as_ref(), Ok(&8));
+    assert_eq!(r.copied(), Ok(8));
+    assert_eq!(Reading::Fault(2).copied(), Err(2));
+}
@@ -0,0 +1,24 @@
+use result_like::{BoolLike, OptionLike, ResultLike};
+
+#[derive(OptionLike)]
+#[option_like(aliases)]
+enum Maybe<T> {
+    Some(T),
+    None,
+}
+
+#[derive(ResultLike)]
+#[result_like(aliases)]
+enum Plain {
+    Ok(u8),
+    Err(String),
+}
+
+#[derive(BoolLike)]
+#[bool_like(aliases)]
+enum Protocol {
+    HTTPOn,
+    HttpOn,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use result_like::BoolLike;
+
+#[derive(BoolLike)]
+enum Payload {
+    Yes(bool),
+    No,
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+use result_like::{OptionLike, ResultLike};
+
+#[derive(OptionLike)]
+#[option_like(skip(clone, mapp))]
+enum Typo<T> {
+    Some(T),
+    None,
+}
+
+#[derive(OptionLike)]
+#[option_like(skip(into_option))]
+enum Required<T> {
+    Some(T),
+    None,
+}
+
+#[derive(ResultLike)]
+#[result_like(only(map), skip(unwrap))]
+enum Both<T, E> {
+    Ok(T),
+    Err(E),
+}
+
+#[derive(ResultLike)]
+#[result_like(exclude(map))]
+enum Unknown<T, E> {
+    Ok(T),
+    Err(E),
+}
+
+fn main() {}
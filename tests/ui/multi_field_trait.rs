@@ -0,0 +1,24 @@
+use result_like::{OptionLike, ResultLike, try_ok, try_some};
+
+#[derive(OptionLike)]
+enum Hit {
+    Found(&'static str, u32),
+    Miss,
+}
+
+#[derive(ResultLike)]
+enum Parsed {
+    Value(u8),
+    Invalid(usize, &'static str),
+}
+
+fn find(hit: Hit) -> Option<u32> {
+    let (_, v) = try_some!(hit);
+    Some(v)
+}
+
+fn parse(parsed: Parsed) -> Result<u8, (usize, &'static str)> {
+    Ok(try_ok!(parsed))
+}
+
+fn main() {}
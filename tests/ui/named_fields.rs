@@ -0,0 +1,15 @@
+use result_like::{OptionLike, ResultLike};
+
+#[derive(OptionLike)]
+enum Lookup<K, V> {
+    Found { key: K, value: V },
+    Missing,
+}
+
+#[derive(ResultLike)]
+enum Outcome<T> {
+    Done { output: T },
+    Failed {},
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use result_like::OptionLike;
+
+#[derive(OptionLike)]
+struct NotEnum(u32);
+
+fn main() {}
@@ -0,0 +1,21 @@
+use result_like::OptionLike;
+
+#[derive(OptionLike)]
+enum UnitFirst {
+    Nothing,
+    None,
+}
+
+#[derive(OptionLike)]
+enum EmptyTuple {
+    Some(),
+    None,
+}
+
+#[derive(OptionLike)]
+enum PayloadSecond {
+    Some(u32),
+    None(u32),
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use result_like::ResultLike;
+
+#[derive(ResultLike)]
+enum UnitErr {
+    Ok(u32),
+    Err,
+}
+
+#[derive(ResultLike)]
+enum NoFields {
+    Ok(),
+    Err(),
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+use result_like::{BoolLike, OptionLike, ResultLike};
+
+#[derive(OptionLike)]
+enum Unknown<T> {
+    #[option_like(something)]
+    Found(T),
+    Missing,
+}
+
+#[derive(ResultLike)]
+enum Twice<T, E> {
+    #[result_like(ok)]
+    Done(T),
+    #[result_like(ok)]
+    Failed(E),
+}
+
+#[derive(BoolLike)]
+enum Both {
+    #[bool_like(true, false)]
+    On,
+    Off,
+}
+
+#[derive(OptionLike)]
+enum WrongShape {
+    #[option_like(some)]
+    Missing,
+    Found(u32),
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use result_like::{BoolLike, OptionLike};
+
+#[derive(BoolLike)]
+#[bool_like(try)]
+enum Switch {
+    On,
+    Off,
+}
+
+// Without the `nightly` feature.
+#[derive(OptionLike)]
+#[option_like(try)]
+enum Maybe<T> {
+    Some(T),
+    None,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use result_like::{BoolLike, OptionLike};
+
+#[derive(OptionLike)]
+enum ThreeVariants {
+    Some(u32),
+    None,
+    Other,
+}
+
+#[derive(BoolLike)]
+enum OneVariant {
+    Yes,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+mod inner {
+    use result_like::OptionLike;
+
+    #[derive(OptionLike)]
+    #[option_like(vis = "pub(self)")]
+    pub enum Hidden<T> {
+        Some(T),
+        None,
+    }
+
+    #[derive(OptionLike)]
+    #[option_like(vis = "public")]
+    pub enum Invalid<T> {
+        Some(T),
+        None,
+    }
+}
+
+fn main() {
+    inner::Hidden::Some(1).unwrap();
+}
@@ -0,0 +1,35 @@
+#![deny(warnings)]
+
+use result_like::{BoolLike, OptionLike, ResultLike};
+
+#[derive(BoolLike, Clone, Copy, Debug, PartialEq)]
+pub enum Flag {
+    On,
+    Off,
+}
+
+#[derive(OptionLike, Debug, PartialEq)]
+pub enum Slot {
+    Filled(u8),
+    Empty,
+}
+
+#[derive(ResultLike, Debug, PartialEq)]
+pub enum Outcome {
+    Pass(u8),
+    Fail(String),
+}
+
+#[derive(ResultLike, Debug, PartialEq)]
+pub enum Checked<T, E> {
+    Valid(T),
+    Invalid(E),
+}
+
+#[test]
+fn test_no_warnings() {
+    assert!(Flag::On.to_bool());
+    assert_eq!(Slot::Filled(1).into_option(), Some(1));
+    assert_eq!(Outcome::Pass(1).ok(), Some(1));
+    assert_eq!(Checked::<u8, String>::Valid(1).ok(), Some(1));
+}